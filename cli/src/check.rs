@@ -0,0 +1,228 @@
+//! Requirements of the `check` subcommand, such as `ubuntu>=22.04` or `arch in [x86_64,aarch64]`.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use os_info::{Architecture, Info, Requirement, Version};
+
+/// Requirement on the operating system or on the processor architecture.
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    /// The requirement as it was given on the command line.
+    text: String,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone)]
+enum Kind {
+    /// Operating system type, edition and version, see `os_info::Requirement`.
+    Os(Requirement),
+    /// The normalized architecture must (or must not, if negated) be one of the listed ones.
+    Arch {
+        negated: bool,
+        architectures: Vec<Architecture>,
+    },
+}
+
+impl Constraint {
+    /// Returns `None` if the constraint is satisfied, otherwise the explanation why it isn't.
+    pub fn check(&self, info: &Info) -> Option<String> {
+        match &self.kind {
+            Kind::Os(requirement) => {
+                if requirement.matches(info) {
+                    return None;
+                }
+
+                let edition_matches = |required: &str| {
+                    info.edition().map_or(false, |e| {
+                        e.to_lowercase().contains(&required.to_lowercase())
+                    })
+                };
+                Some(if requirement.os_type() != info.os_type() {
+                    format!("the operating system is {}", info.os_type())
+                } else if !requirement.edition().map_or(true, edition_matches) {
+                    format!("the edition is {}", info.edition().unwrap_or("unknown"))
+                } else if let Version::Unknown = info.version() {
+                    "the version is unknown".to_owned()
+                } else {
+                    format!("the version is {}", info.version())
+                })
+            }
+            Kind::Arch {
+                negated,
+                architectures,
+            } => {
+                // An unknown architecture satisfies neither `in` nor `not in`.
+                let architecture = info.normalized_architecture();
+                if architecture.map_or(false, |a| architectures.contains(&a) != *negated) {
+                    return None;
+                }
+
+                Some(format!(
+                    "the architecture is {}",
+                    info.architecture().unwrap_or("unknown")
+                ))
+            }
+        }
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl FromStr for Constraint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind = match parse_arch(s.trim())? {
+            Some(kind) => kind,
+            None => Kind::Os(s.parse().map_err(|e| format!("{e}"))?),
+        };
+
+        Ok(Self {
+            text: s.trim().to_owned(),
+            kind,
+        })
+    }
+}
+
+/// Parses `arch in [..]`, `arch not in [..]`, `arch = ..` and `arch != ..`. Returns `None` if the
+/// string isn't an architecture constraint, because `arch` is also the Arch Linux identifier.
+fn parse_arch(s: &str) -> Result<Option<Kind>, String> {
+    let rest = match ["architecture", "arch"]
+        .iter()
+        .find_map(|key| s.strip_prefix(key))
+    {
+        Some(rest) => rest.trim_start(),
+        None => return Ok(None),
+    };
+
+    let (negated, architectures) = if let Some(list) = list_after(rest, "not in") {
+        (true, parse_list(list)?)
+    } else if let Some(list) = list_after(rest, "in") {
+        (false, parse_list(list)?)
+    } else {
+        let (negated, value) = match rest.strip_prefix("!=") {
+            Some(value) => (true, value),
+            None => match rest.strip_prefix("==").or_else(|| rest.strip_prefix('=')) {
+                Some(value) => (false, value),
+                None => return Ok(None),
+            },
+        };
+        match value.parse::<Architecture>() {
+            Ok(architecture) => (negated, vec![architecture]),
+            Err(_) => return Ok(None),
+        }
+    };
+
+    Ok(Some(Kind::Arch {
+        negated,
+        architectures,
+    }))
+}
+
+fn list_after<'a>(s: &'a str, keyword: &str) -> Option<&'a str> {
+    let list = s.strip_prefix(keyword)?.trim_start();
+    if list.starts_with('[') {
+        Some(list)
+    } else {
+        None
+    }
+}
+
+fn parse_list(list: &str) -> Result<Vec<Architecture>, String> {
+    let items = list
+        .trim_end()
+        .strip_prefix('[')
+        .and_then(|l| l.strip_suffix(']'))
+        .ok_or("expected a list of architectures in brackets, such as [x86_64, aarch64]")?;
+
+    let architectures = items
+        .split(',')
+        .map(str::trim)
+        .filter(|i| !i.is_empty())
+        .map(|i| i.parse::<Architecture>().map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    if architectures.is_empty() {
+        return Err("empty list of architectures".to_owned());
+    }
+    Ok(architectures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_info::Type;
+
+    #[test]
+    fn parse() {
+        let data = [
+            ("arch in [x86_64,aarch64]", Some((false, 2))),
+            ("arch in [ x86_64, arm64, ]", Some((false, 2))),
+            ("architecture not in [riscv64]", Some((true, 1))),
+            ("arch = amd64", Some((false, 1))),
+            ("arch==x86_64", Some((false, 1))),
+            ("arch != s390x", Some((true, 1))),
+            ("ubuntu>=22.04", None),
+            ("arch", None),
+            ("archarm", None),
+        ];
+
+        for (s, expected) in &data {
+            let constraint: Constraint = s.parse().unwrap();
+            let actual = match &constraint.kind {
+                Kind::Arch {
+                    negated,
+                    architectures,
+                } => Some((*negated, architectures.len())),
+                Kind::Os(_) => None,
+            };
+            assert_eq!(*expected, actual, "{}", s);
+            assert_eq!(*s, constraint.to_string());
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let data = [
+            ("arch in [x86_64", "expected a list"),
+            ("arch in []", "empty list"),
+            ("arch in [vax]", "unknown architecture: 'vax'"),
+            ("plan9 >=4", "unknown operating system"),
+            ("ubuntu >=", "missing version"),
+        ];
+
+        for (s, expected) in &data {
+            let error = s.parse::<Constraint>().unwrap_err();
+            assert!(error.to_lowercase().contains(expected), "{}: {}", s, error);
+        }
+    }
+
+    #[test]
+    fn check() {
+        let info = Info::with_type(Type::Ubuntu);
+        let data = [
+            ("ubuntu", None),
+            ("debian", Some("the operating system is Ubuntu")),
+            ("ubuntu >=22.04", Some("the version is unknown")),
+            ("ubuntu (Pro)", Some("the edition is unknown")),
+            ("arch in [x86_64]", Some("the architecture is unknown")),
+            ("arch not in [x86_64]", Some("the architecture is unknown")),
+        ];
+
+        for (s, expected) in &data {
+            let constraint: Constraint = s.parse().unwrap();
+            assert_eq!(
+                expected.map(str::to_owned),
+                constraint.check(&info),
+                "{}",
+                s
+            );
+        }
+    }
+}
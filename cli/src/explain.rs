@@ -0,0 +1,51 @@
+//! Human-readable detection report for `--explain`.
+
+use std::fmt::Write;
+
+use os_info::DetectionReport;
+
+/// Lists the lookups grouped by the property they were made for, followed by the result.
+pub fn explain(report: &DetectionReport) -> String {
+    let mut output = String::new();
+
+    let mut properties = Vec::new();
+    for attempt in report.attempts() {
+        if !properties.contains(&attempt.property()) {
+            properties.push(attempt.property());
+        }
+    }
+
+    for property in properties {
+        writeln!(output, "{}:", capitalize(&property.to_string())).unwrap();
+        for attempt in report
+            .attempts()
+            .iter()
+            .filter(|a| a.property() == property)
+        {
+            match attempt.source() {
+                Some(source) => write!(output, "  {} ({source})", attempt.target()),
+                None => write!(output, "  {}", attempt.target()),
+            }
+            .unwrap();
+            writeln!(output, ": {}", attempt.outcome()).unwrap();
+        }
+    }
+
+    if report.attempts().is_empty() {
+        writeln!(
+            output,
+            "Detection sources aren't recorded on this platform."
+        )
+        .unwrap();
+    }
+    writeln!(output, "Result: {}", report.info()).unwrap();
+    output
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,139 @@
+//! Shell variable definitions shared by `--format env` and `eval "$(os_info --export)"`.
+
+use std::fmt::Write;
+
+use os_info::{Bitness, Info, Version};
+
+use crate::format::SCHEMA_VERSION;
+
+/// Prefix of the variable names.
+pub const DEFAULT_PREFIX: &str = "OS_";
+
+/// Returns the `NAME='value'` lines used by the `env` format.
+pub fn assignments(info: &Info, prefix: &str) -> String {
+    lines(info, prefix, "")
+}
+
+/// Returns the `export NAME='value'` lines.
+pub fn export(info: &Info, prefix: &str) -> String {
+    lines(info, prefix, "export ")
+}
+
+fn lines(info: &Info, prefix: &str, keyword: &str) -> String {
+    let mut lines = String::new();
+    for (name, value) in &variables(info) {
+        writeln!(lines, "{keyword}{prefix}{name}={}", quote(value)).unwrap();
+    }
+    lines
+}
+
+/// Returns the variable names without the prefix along with their values. The names are the same
+/// for every system and unknown values are empty strings, so the variables are always defined.
+fn variables(info: &Info) -> [(&'static str, String); 13] {
+    let optional = |value: Option<String>| value.unwrap_or_default();
+    let version = match info.version() {
+        Version::Unknown => String::new(),
+        version => version.to_string(),
+    };
+    let major = info
+        .version()
+        .components()
+        .and_then(|c| c.first().map(u64::to_string))
+        .unwrap_or_default();
+    let bitness = match info.bitness() {
+        Bitness::X32 => "32",
+        Bitness::X64 => "64",
+        _ => "",
+    };
+    let package_managers: Vec<_> = info
+        .package_managers()
+        .iter()
+        .map(|p| p.to_string())
+        .collect();
+
+    [
+        ("SCHEMA_VERSION", SCHEMA_VERSION.to_string()),
+        ("TYPE", info.os_type().to_string()),
+        ("VERSION", version),
+        ("VERSION_MAJOR", major),
+        ("CODENAME", optional(info.codename().map(str::to_owned))),
+        ("EDITION", optional(info.edition().map(str::to_owned))),
+        ("BASED_ON", optional(info.based_on().map(|t| t.to_string()))),
+        ("BITNESS", bitness.to_owned()),
+        ("ARCH", optional(info.architecture().map(str::to_owned))),
+        (
+            "KERNEL_VERSION",
+            optional(info.kernel_version().map(|k| k.to_string())),
+        ),
+        ("WSL", optional(info.wsl().map(|w| w.to_string()))),
+        // Multiple package managers are kept in one variable, separated by spaces.
+        ("PACKAGE_MANAGERS", package_managers.join(" ")),
+        (
+            "DEPLOYMENT",
+            optional(info.deployment().map(|d| d.to_string())),
+        ),
+    ]
+}
+
+/// Checks that the prefix forms valid shell variable names.
+pub fn parse_prefix(prefix: &str) -> Result<String, String> {
+    let starts_with_digit = prefix.chars().next().map_or(false, |c| c.is_ascii_digit());
+    if starts_with_digit
+        || !prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(format!("'{prefix}' isn't a valid shell variable prefix"));
+    }
+    Ok(prefix.to_owned())
+}
+
+/// Single-quotes the value, so the shell doesn't expand anything in it.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_info::Type;
+
+    #[test]
+    fn keys() {
+        let info = Info::with_type(Type::Ubuntu);
+        let expected = "OS_SCHEMA_VERSION='1'\nOS_TYPE='Ubuntu'\nOS_VERSION=''\n\
+            OS_VERSION_MAJOR=''\nOS_CODENAME=''\nOS_EDITION=''\nOS_BASED_ON=''\nOS_BITNESS=''\n\
+            OS_ARCH=''\nOS_KERNEL_VERSION=''\nOS_WSL=''\nOS_PACKAGE_MANAGERS='apt'\n\
+            OS_DEPLOYMENT=''\n";
+        assert_eq!(expected, assignments(&info, DEFAULT_PREFIX));
+
+        let exported = export(&info, "HOST_");
+        assert!(exported.starts_with("export HOST_SCHEMA_VERSION='1'\nexport HOST_TYPE='Ubuntu'\n"));
+        assert_eq!(13, exported.lines().count());
+    }
+
+    #[test]
+    fn quote_value() {
+        let data = [
+            ("", "''"),
+            ("x86_64", "'x86_64'"),
+            ("Debian GNU/Linux", "'Debian GNU/Linux'"),
+            ("$(rm -rf /)", "'$(rm -rf /)'"),
+            ("it's", r"'it'\''s'"),
+        ];
+
+        for (value, expected) in &data {
+            assert_eq!(*expected, quote(value));
+        }
+    }
+
+    #[test]
+    fn prefix() {
+        assert_eq!(Ok("OS_".to_owned()), parse_prefix("OS_"));
+        assert_eq!(Ok("_host2_".to_owned()), parse_prefix("_host2_"));
+        assert_eq!(Ok(String::new()), parse_prefix(""));
+        assert!(parse_prefix("2OS_").is_err());
+        assert!(parse_prefix("OS-").is_err());
+        assert!(parse_prefix("OS $").is_err());
+    }
+}
@@ -0,0 +1,88 @@
+//! Machine-readable output formats.
+
+use std::{error::Error, str::FromStr};
+
+use os_info::Info;
+use serde::Serialize;
+
+use crate::{export, template::Template};
+
+/// Version of the serialized output layout. It is incremented when a field is renamed or removed,
+/// adding new fields doesn't change it.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Format used to print the operating system information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Format {
+    /// JSON object.
+    Json,
+    /// YAML document.
+    Yaml,
+    /// TOML document.
+    Toml,
+    /// `OS_KEY='value'` lines with a fixed set of keys, the same as `--export` defines.
+    Env,
+    /// User-defined template, see `Template` for the syntax.
+    Template(Template),
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => return Ok(Format::Json),
+            "yaml" => return Ok(Format::Yaml),
+            "toml" => return Ok(Format::Toml),
+            "env" => return Ok(Format::Env),
+            _ => {}
+        }
+
+        let template: Template = s.parse()?;
+        if !template.has_fields() {
+            return Err(format!(
+                "unknown format '{s}', expected json, yaml, toml, env or a template such as \
+                '{{type}} {{version}}'"
+            ));
+        }
+        Ok(Format::Template(template))
+    }
+}
+
+#[derive(Serialize)]
+struct Output<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    info: &'a Info,
+}
+
+/// Serializes the given information along with the schema version, or substitutes it into the
+/// template.
+pub fn render(info: &Info, format: &Format) -> Result<String, Box<dyn Error>> {
+    let output = Output {
+        schema_version: SCHEMA_VERSION,
+        info,
+    };
+
+    Ok(match format {
+        Format::Json => serde_json::to_string_pretty(&output)? + "\n",
+        Format::Yaml => serde_yaml::to_string(&output)?,
+        Format::Toml => toml::to_string(&output)?,
+        Format::Env => export::assignments(info, export::DEFAULT_PREFIX),
+        Format::Template(template) => template.render(info) + "\n",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_info::Type;
+
+    #[test]
+    fn env_keys() {
+        let info = Info::with_type(Type::Ubuntu);
+        let output = render(&info, &Format::Env).unwrap();
+        assert_eq!(export::assignments(&info, export::DEFAULT_PREFIX), output);
+        assert!(output.starts_with("OS_SCHEMA_VERSION='1'\nOS_TYPE='Ubuntu'\n"));
+    }
+}
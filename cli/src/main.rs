@@ -5,8 +5,19 @@
 
 #![deny(missing_docs, unsafe_code)]
 
-use clap::Parser;
+mod check;
+mod explain;
+mod export;
+mod format;
+mod template;
+
+use std::process;
+
+use clap::{Parser, Subcommand};
 use log::warn;
+use os_info::Detector;
+
+use crate::{check::Constraint, format::Format};
 
 #[derive(Parser)]
 #[clap(about, version)]
@@ -26,15 +37,86 @@ struct Options {
     /// Show OS arch.
     #[clap(short = 'A', long = "Arch")]
     architecture: bool,
+    /// Print the full OS information in the given format (json, yaml, toml or env), or fill in a
+    /// template such as '{type} {version.major}.{version.minor} ({arch})'. Unknown fields are
+    /// empty unless a default is given: '{codename|none}'. Use '{{' and '}}' for literal braces.
+    #[clap(long, value_name = "FORMAT")]
+    format: Option<Format>,
+    /// Print shell commands that export the OS information, for use with `eval`.
+    #[clap(long, conflicts_with = "format")]
+    export: bool,
+    /// Prefix of the exported variable names.
+    #[clap(long, requires = "export", default_value = export::DEFAULT_PREFIX, value_parser = export::parse_prefix)]
+    prefix: String,
+    /// List every detection source that was tried, what it returned and which one was used. The
+    /// requirements of the `check` subcommand are checked afterwards.
+    #[clap(long, conflicts_with_all = &["format", "export"])]
+    explain: bool,
+    #[clap(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check that the OS satisfies all requirements, exit with 1 and explain the failed ones
+    /// otherwise.
+    Check {
+        /// OS requirement, such as 'ubuntu>=22.04' or 'windows (Pro) >=10.0.19041', or
+        /// architecture requirement, such as 'arch in [x86_64,aarch64]' or 'arch != x86'.
+        #[clap(required = true, value_name = "REQUIREMENT")]
+        requirements: Vec<Constraint>,
+    },
 }
 
 fn main() {
     env_logger::init();
 
     let options = Options::parse();
-    let info = os_info::get();
 
-    if options.all
+    // The requirements are still checked after the explanation, so that a failing check isn't
+    // reported as satisfied.
+    let info = if options.explain {
+        let report = Detector::new().explain();
+        print!("{}", explain::explain(&report));
+        if options.command.is_none() {
+            return;
+        }
+        report.into_info()
+    } else {
+        os_info::get()
+    };
+
+    if let Some(Command::Check { requirements }) = &options.command {
+        let mut satisfied = true;
+        for requirement in requirements {
+            if let Some(reason) = requirement.check(&info) {
+                eprintln!("Requirement '{requirement}' is not satisfied: {reason}");
+                satisfied = false;
+            }
+        }
+        process::exit(if satisfied { 0 } else { 1 });
+    }
+
+    if options.export {
+        print!("{}", export::export(&info, &options.prefix));
+    } else if let Some(format) = &options.format {
+        if options.all
+            || options.type_
+            || options.os_version
+            || options.bitness
+            || options.architecture
+        {
+            warn!("--format supersedes all other options");
+        }
+
+        match format::render(&info, format) {
+            Ok(output) => print!("{output}"),
+            Err(e) => {
+                eprintln!("Unable to serialize OS information: {e}");
+                process::exit(1);
+            }
+        }
+    } else if options.all
         || !(options.type_ || options.os_version || options.bitness || options.architecture)
     {
         if options.type_ || options.os_version || options.bitness || options.architecture {
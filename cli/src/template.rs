@@ -0,0 +1,304 @@
+//! User-defined output templates, such as `{type} {version.major}.{version.minor} ({arch})`.
+
+use std::{iter::Peekable, str::Chars, str::FromStr};
+
+use os_info::{Bitness, Date, Info, Version};
+
+/// Fields that can be used in a template. Any os-release key can also be used with the
+/// `os_release.` prefix, for example `{os_release.VARIANT_ID}`.
+const FIELDS: [&str; 26] = [
+    "type",
+    "family",
+    "version",
+    "version.major",
+    "version.minor",
+    "version.patch",
+    "version.revision",
+    "version.pre_release",
+    "version.build",
+    "edition",
+    "codename",
+    "release_name",
+    "based_on",
+    "bitness",
+    "arch",
+    "arch.normalized",
+    "kernel",
+    "kernel.version",
+    "kernel.suffix",
+    "wsl",
+    "package_manager",
+    "package_managers",
+    "deployment",
+    "immutable",
+    "support_status",
+    "end_of_life",
+];
+
+/// Alternative field names matching the serialized `Info` fields.
+const ALIASES: [(&str, &str); 3] = [
+    ("os_type", "type"),
+    ("architecture", "arch"),
+    ("kernel_version", "kernel"),
+];
+
+const OS_RELEASE_PREFIX: &str = "os_release.";
+
+/// Parsed template.
+///
+/// Fields are written in braces and can have a default value that is used when the field is
+/// unknown: `{codename|none}`. `{{` and `}}` are literal braces, `\n`, `\t` and `\\` are the
+/// newline, tab and backslash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Field {
+        name: String,
+        default: Option<String>,
+    },
+}
+
+impl Template {
+    /// Substitutes the fields with the values from the given information.
+    pub fn render(&self, info: &Info) -> String {
+        let mut result = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => result.push_str(text),
+                Segment::Field { name, default } => {
+                    if let Some(value) = field(info, name).filter(|v| !v.is_empty()) {
+                        result.push_str(&value);
+                    } else if let Some(default) = default {
+                        result.push_str(default);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns `true` if the template contains at least one field.
+    pub fn has_fields(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|s| matches!(s, Segment::Field { .. }))
+    }
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = s.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '{' => {
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut text)));
+                    }
+                    segments.push(parse_field(&mut chars)?);
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '}' => return Err("unmatched '}', use '}}' for a literal brace".to_owned()),
+                '\\' => match chars.peek() {
+                    Some('n') => {
+                        chars.next();
+                        text.push('\n');
+                    }
+                    Some('t') => {
+                        chars.next();
+                        text.push('\t');
+                    }
+                    Some('\\') => {
+                        chars.next();
+                        text.push('\\');
+                    }
+                    // Other backslashes are kept as is, so Windows paths don't need escaping.
+                    _ => text.push('\\'),
+                },
+                _ => text.push(c),
+            }
+        }
+
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+        Ok(Self { segments })
+    }
+}
+
+/// Parses the `name|default}` part of a field after the opening brace.
+fn parse_field(chars: &mut Peekable<Chars>) -> Result<Segment, String> {
+    let mut content = String::new();
+    loop {
+        match chars.next() {
+            Some('}') => break,
+            Some(c) => content.push(c),
+            None => return Err(format!("unclosed field '{{{content}'")),
+        }
+    }
+
+    let (name, default) = match content.find('|') {
+        Some(index) => (&content[..index], Some(content[index + 1..].to_owned())),
+        None => (content.as_str(), None),
+    };
+    let name = name.trim();
+    let name = ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |(_, field)| field);
+
+    let is_os_release_key = name
+        .strip_prefix(OS_RELEASE_PREFIX)
+        .map_or(false, |key| !key.is_empty());
+    if !is_os_release_key && !FIELDS.contains(&name) {
+        return Err(format!(
+            "unknown field '{name}', expected one of: {}, {OS_RELEASE_PREFIX}<KEY>",
+            FIELDS.join(", ")
+        ));
+    }
+
+    Ok(Segment::Field {
+        name: name.to_owned(),
+        default,
+    })
+}
+
+/// Returns the value of the field, or `None` if it is unknown.
+fn field(info: &Info, name: &str) -> Option<String> {
+    let component = |index| {
+        info.version()
+            .components()
+            .and_then(|c| c.get(index).map(u64::to_string))
+    };
+
+    match name {
+        "type" => Some(info.os_type().to_string()),
+        "family" => Some(info.os_type().family().to_string()),
+        "version" => match info.version() {
+            Version::Unknown => None,
+            version => Some(version.to_string()),
+        },
+        "version.major" => component(0),
+        "version.minor" => component(1),
+        "version.patch" => component(2),
+        "version.revision" => info.version().revision().map(|r| r.to_string()),
+        "version.pre_release" => info.version().pre_release().map(str::to_owned),
+        "version.build" => info.version().build().map(str::to_owned),
+        "edition" => info.edition().map(str::to_owned),
+        "codename" => info.codename().map(str::to_owned),
+        "release_name" => info.release_name().map(str::to_owned),
+        "based_on" => info.based_on().map(|t| t.to_string()),
+        "bitness" => match info.bitness() {
+            Bitness::Unknown => None,
+            bitness => Some(bitness.to_string()),
+        },
+        "arch" => info.architecture().map(str::to_owned),
+        "arch.normalized" => info.normalized_architecture().map(|a| a.to_string()),
+        "kernel" => info.kernel_version().map(|k| k.release().to_owned()),
+        "kernel.version" => info.kernel_version().map(|k| k.version().to_string()),
+        "kernel.suffix" => info
+            .kernel_version()
+            .and_then(|k| k.suffix())
+            .map(str::to_owned),
+        "wsl" => info.wsl().map(|w| w.to_string()),
+        "package_manager" => info.package_managers().first().map(|p| p.to_string()),
+        "package_managers" => {
+            let managers: Vec<_> = info
+                .package_managers()
+                .iter()
+                .map(|p| p.to_string())
+                .collect();
+            Some(managers.join(" "))
+        }
+        "deployment" => info.deployment().map(|d| d.to_string()),
+        "immutable" => Some(info.is_immutable().to_string()),
+        "support_status" => info.support_status(Date::today()).map(|s| s.to_string()),
+        // The os-release `SUPPORT_END` date takes precedence, as in `Info::support_status`.
+        "end_of_life" => os_release(info, "SUPPORT_END").or_else(|| {
+            info.lifecycle()
+                .and_then(|l| l.end_of_life())
+                .map(|d| d.to_string())
+        }),
+        _ => os_release(info, name.strip_prefix(OS_RELEASE_PREFIX)?),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn os_release(info: &Info, key: &str) -> Option<String> {
+    info.os_release()?.get(key).map(str::to_owned)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn os_release(_: &Info, _: &str) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_info::Type;
+
+    #[test]
+    fn render() {
+        let info = Info::with_type(Type::Ubuntu);
+        let data = [
+            ("{type}", "Ubuntu"),
+            ("{os_type} {family}", "Ubuntu Debian"),
+            ("{version}", ""),
+            ("{version|unknown}", "unknown"),
+            ("{version.major|0}.{version.minor|0}", "0.0"),
+            ("{codename|}", ""),
+            ("{ type }", "Ubuntu"),
+            ("{edition|a|b}", "a|b"),
+            ("{{{type}}}", "{Ubuntu}"),
+            ("{type}\\n{arch|?}\\t\\\\", "Ubuntu\n?\t\\"),
+            ("C:\\os\\{type}", "C:\\os\\Ubuntu"),
+            ("{os_release.ID|none}", "none"),
+            ("{immutable}", "false"),
+        ];
+
+        for (template, expected) in &data {
+            let template: Template = template.parse().unwrap();
+            assert_eq!(*expected, template.render(&info));
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let data = [
+            ("{unknown}", "unknown field 'unknown'"),
+            ("{os_release.}", "unknown field 'os_release.'"),
+            ("{}", "unknown field ''"),
+            ("{type", "unclosed field '{type'"),
+            ("type}", "unmatched '}'"),
+        ];
+
+        for (template, expected) in &data {
+            let error = template.parse::<Template>().unwrap_err();
+            assert!(error.starts_with(expected), "{}: {}", template, error);
+        }
+    }
+
+    #[test]
+    fn has_fields() {
+        assert!("{type}".parse::<Template>().unwrap().has_fields());
+        assert!(!"json".parse::<Template>().unwrap().has_fields());
+        assert!(!"{{type}}".parse::<Template>().unwrap().has_fields());
+    }
+}
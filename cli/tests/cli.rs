@@ -81,6 +81,205 @@ fn bitness_long() {
         .stdout(bitness_predicate());
 }
 
+#[test]
+fn format_json() {
+    let output = Command::new(BIN_NAME)
+        .args(["--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(Some(1), value["schema_version"].as_u64());
+    assert!(value["os_type"].is_string());
+    assert!(value["bitness"].is_string());
+}
+
+#[test]
+fn format_yaml() {
+    Command::new(BIN_NAME)
+        .args(["--format", "yaml"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("schema_version: 1\n")
+                .and(predicate::str::contains("\nos_type: ")),
+        );
+}
+
+#[test]
+fn format_toml() {
+    Command::new(BIN_NAME)
+        .args(["--format", "toml"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("schema_version = 1\n")
+                .and(predicate::str::contains("\nos_type = ")),
+        );
+}
+
+#[test]
+fn format_env() {
+    Command::new(BIN_NAME)
+        .args(["--format", "env"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("OS_SCHEMA_VERSION='1'\nOS_TYPE='")
+                .and(predicate::str::contains("\nOS_VERSION='"))
+                .and(predicate::str::contains("\nOS_DEPLOYMENT='"))
+                .and(predicate::str::contains("export").not()),
+        );
+}
+
+#[test]
+fn format_unknown() {
+    Command::new(BIN_NAME)
+        .args(["--format", "xml"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn format_template() {
+    Command::new(BIN_NAME)
+        .args(["--format", "{{{type}}} {version|unknown} {codename|-}"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^\{[^{}]+\} .+ .+\n$").unwrap());
+}
+
+#[test]
+fn format_template_unknown_field() {
+    Command::new(BIN_NAME)
+        .args(["--format", "{type} {unknown}"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown field 'unknown'"));
+}
+
+#[test]
+fn export() {
+    Command::new(BIN_NAME)
+        .arg("--export")
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("export OS_SCHEMA_VERSION='1'\nexport OS_TYPE='")
+                .and(predicate::str::contains("\nexport OS_VERSION='"))
+                .and(predicate::str::contains("\nexport OS_VERSION_MAJOR='"))
+                .and(predicate::str::contains("\nexport OS_CODENAME='"))
+                .and(predicate::str::contains("\nexport OS_EDITION='"))
+                .and(predicate::str::contains("\nexport OS_BITNESS='"))
+                .and(predicate::str::contains("\nexport OS_ARCH='")),
+        );
+}
+
+#[test]
+fn export_prefix() {
+    Command::new(BIN_NAME)
+        .args(["--export", "--prefix", "HOST_"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("export HOST_SCHEMA_VERSION='1'\nexport HOST_TYPE='")
+                .and(predicate::str::contains("OS_TYPE").not()),
+        );
+}
+
+#[test]
+fn export_invalid_prefix() {
+    Command::new(BIN_NAME)
+        .args(["--export", "--prefix", "OS-"])
+        .assert()
+        .failure();
+}
+
+#[cfg(unix)]
+#[test]
+fn export_eval() {
+    let script = format!("eval \"$('{BIN_NAME}' --export)\" && echo \"$OS_TYPE\"");
+    let output = std::process::Command::new("sh")
+        .args(["-c", &script])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).trim().is_empty());
+}
+
+#[test]
+fn check_satisfied() {
+    let output = Command::new(BIN_NAME)
+        .args(["--format", "{type}"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let os_type = String::from_utf8(output).unwrap();
+
+    Command::new(BIN_NAME)
+        .args(["check", os_type.trim()])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn check_not_satisfied() {
+    Command::new(BIN_NAME)
+        .args(["check", "windows", "macos >=14"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "is not satisfied: the operating system is",
+        ));
+}
+
+#[test]
+fn check_invalid_requirement() {
+    Command::new(BIN_NAME)
+        .args(["check", "arch in [vax]"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("unknown architecture: 'vax'"));
+}
+
+#[test]
+fn explain() {
+    let predicate = if cfg!(target_os = "linux") {
+        predicate::str::starts_with("Distribution:\n")
+            .and(predicate::str::contains("(os-release): "))
+            .and(predicate::str::contains("used: "))
+            .boxed()
+    } else if cfg!(windows) {
+        predicate::str::contains("aren't recorded on this platform").boxed()
+    } else {
+        predicate::str::contains("used: ").boxed()
+    };
+
+    Command::new(BIN_NAME)
+        .arg("--explain")
+        .assert()
+        .success()
+        .stdout(predicate.and(predicate::str::contains("\nResult: ")));
+}
+
+#[test]
+fn explain_check() {
+    Command::new(BIN_NAME)
+        .args(["--explain", "check", "windows", "macos >=14"])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("\nResult: "))
+        .stderr(predicate::str::contains(
+            "is not satisfied: the operating system is",
+        ));
+}
+
 fn all_predicate() -> impl Predicate<str> {
     predicate::str::starts_with("OS information:")
         .and(predicate::str::contains("Type"))
@@ -4,36 +4,68 @@ use log::{error, trace};
 
 use crate::{
     bitness,
-    uname::{uname, UnameField},
-    Info, Type, Version,
+    report::{Attempt, Property},
+    uname::{self, UnameField},
+    DetectionReport, Detector, Info, KernelVersion, Type, Version,
 };
 
 pub fn current_platform() -> Info {
     trace!("aix::current_platform is called");
+    explain(&Detector::new()).into_info()
+}
+
+pub fn explain(detector: &Detector) -> DetectionReport {
+    trace!("aix::explain is called with {:?}", detector);
+    let mut attempts = Vec::new();
 
-    let version = get_version()
+    let version = get_version(detector, &mut attempts)
         .map(Version::from_string)
         .unwrap_or_else(|| Version::Unknown);
 
     let info = Info {
-        os_type: get_os(),
+        os_type: get_os(detector, &mut attempts),
         version,
-        bitness: bitness::get(),
+        bitness: bitness::lookup(detector, &mut attempts),
+        kernel_version: uname::lookup(
+            UnameField::Release,
+            Property::KernelVersion,
+            detector,
+            &mut attempts,
+        )
+        .map(KernelVersion::parse),
         ..Default::default()
     };
 
     trace!("Returning {:?}", info);
-    info
+    DetectionReport { info, attempts }
 }
 
-fn get_version() -> Option<String> {
-    let major = uname(UnameField::Version)?;
-    let minor = uname(UnameField::Release).unwrap_or(String::from("0"));
+fn get_version(detector: &Detector, attempts: &mut Vec<Attempt>) -> Option<String> {
+    let major = uname::lookup(
+        UnameField::Version,
+        Property::Distribution,
+        detector,
+        attempts,
+    )?;
+    let minor = uname::lookup(
+        UnameField::Release,
+        Property::Distribution,
+        detector,
+        attempts,
+    )
+    .unwrap_or(String::from("0"));
     Some(format!("{}.{}", major, minor))
 }
 
-fn get_os() -> Type {
-    match uname(UnameField::Sysname).as_deref() {
+fn get_os(detector: &Detector, attempts: &mut Vec<Attempt>) -> Type {
+    match uname::lookup(
+        UnameField::Sysname,
+        Property::Distribution,
+        detector,
+        attempts,
+    )
+    .as_deref()
+    {
         Some("AIX") => Type::AIX,
         _ => Type::Unknown,
     }
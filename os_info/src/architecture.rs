@@ -1,16 +1,279 @@
-use crate::uname::{uname, UnameField};
+// spell-checker:ignore armhf, armel, earmv, loongarch, ppc64el
 
-pub fn get() -> Option<String> {
-    uname(UnameField::Machine)
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::Bitness;
+
+/// Normalized processor architecture.
+///
+/// Different platforms report the same architecture using different names (for example `amd64`
+/// and `x86_64`), this type can be parsed from all of them.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::{Architecture, Bitness};
+///
+/// let arch: Architecture = "amd64".parse().unwrap();
+/// assert_eq!(Architecture::X86_64, arch);
+/// assert_eq!("x86_64", arch.to_string());
+/// assert_eq!(Bitness::X64, arch.bitness());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(non_camel_case_types)]
+#[non_exhaustive]
+pub enum Architecture {
+    /// 32-bit x86 (`i386`, `i686`).
+    X86,
+    /// 64-bit x86 (`x86_64`, `amd64`, `i86pc`).
+    X86_64,
+    /// 32-bit ARM (`arm`, `armv7l`).
+    Arm,
+    /// 64-bit ARM (`aarch64`, `arm64`).
+    Aarch64,
+    /// Intel Itanium (`ia64`).
+    Ia64,
+    /// 32-bit RISC-V (`riscv32`).
+    Riscv32,
+    /// 64-bit RISC-V (`riscv64`).
+    Riscv64,
+    /// 32-bit PowerPC (`ppc`, `powerpc`).
+    Ppc,
+    /// 64-bit big-endian PowerPC (`ppc64`, `powerpc64`).
+    Ppc64,
+    /// 64-bit little-endian PowerPC (`ppc64le`, `powerpc64le`, `ppc64el`).
+    Ppc64le,
+    /// 31-bit IBM S/390 (`s390`).
+    S390,
+    /// 64-bit IBM Z (`s390x`).
+    S390x,
+    /// 32-bit MIPS (`mips`, `mipsel`).
+    Mips,
+    /// 64-bit MIPS (`mips64`, `mips64el`).
+    Mips64,
+    /// 64-bit LoongArch (`loongarch64`).
+    Loongarch64,
+    /// 32-bit SPARC (`sparc`).
+    Sparc,
+    /// 64-bit SPARC (`sparc64`).
+    Sparc64,
+    /// DEC Alpha (`alpha`).
+    Alpha,
+    /// Motorola 68000 (`m68k`).
+    M68k,
+}
+
+impl Architecture {
+    /// Returns the bitness of the architecture.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Architecture, Bitness};
+    ///
+    /// assert_eq!(Bitness::X32, Architecture::Arm.bitness());
+    /// assert_eq!(Bitness::X64, Architecture::Aarch64.bitness());
+    /// ```
+    pub fn bitness(self) -> Bitness {
+        match self {
+            Architecture::X86
+            | Architecture::Arm
+            | Architecture::Riscv32
+            | Architecture::Ppc
+            | Architecture::S390
+            | Architecture::Mips
+            | Architecture::Sparc
+            | Architecture::M68k => Bitness::X32,
+            Architecture::X86_64
+            | Architecture::Aarch64
+            | Architecture::Ia64
+            | Architecture::Riscv64
+            | Architecture::Ppc64
+            | Architecture::Ppc64le
+            | Architecture::S390x
+            | Architecture::Mips64
+            | Architecture::Loongarch64
+            | Architecture::Sparc64
+            | Architecture::Alpha => Bitness::X64,
+        }
+    }
+}
+
+impl Display for Architecture {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Architecture::X86 => "x86",
+            Architecture::X86_64 => "x86_64",
+            Architecture::Arm => "arm",
+            Architecture::Aarch64 => "aarch64",
+            Architecture::Ia64 => "ia64",
+            Architecture::Riscv32 => "riscv32",
+            Architecture::Riscv64 => "riscv64",
+            Architecture::Ppc => "ppc",
+            Architecture::Ppc64 => "ppc64",
+            Architecture::Ppc64le => "ppc64le",
+            Architecture::S390 => "s390",
+            Architecture::S390x => "s390x",
+            Architecture::Mips => "mips",
+            Architecture::Mips64 => "mips64",
+            Architecture::Loongarch64 => "loongarch64",
+            Architecture::Sparc => "sparc",
+            Architecture::Sparc64 => "sparc64",
+            Architecture::Alpha => "alpha",
+            Architecture::M68k => "m68k",
+        })
+    }
 }
 
+impl FromStr for Architecture {
+    type Err = ParseArchitectureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let arch = s.trim().to_ascii_lowercase();
+        Ok(match arch.as_str() {
+            "x86" | "i386" | "i486" | "i586" | "i686" => Architecture::X86,
+            // Solaris and illumos report `i86pc` for the x86 platform, which is 64-bit only on the
+            // supported releases.
+            "x86_64" | "x86-64" | "amd64" | "x64" | "i86pc" => Architecture::X86_64,
+            "aarch64" | "aarch64_be" | "arm64" | "arm64e" => Architecture::Aarch64,
+            "arm" | "armhf" | "armel" | "earmv7hf" => Architecture::Arm,
+            a if a.starts_with("armv") => Architecture::Arm,
+            "ia64" => Architecture::Ia64,
+            "riscv32" => Architecture::Riscv32,
+            "riscv64" => Architecture::Riscv64,
+            "ppc" | "powerpc" => Architecture::Ppc,
+            "ppc64" | "powerpc64" => Architecture::Ppc64,
+            "ppc64le" | "powerpc64le" | "ppc64el" => Architecture::Ppc64le,
+            "s390" => Architecture::S390,
+            "s390x" => Architecture::S390x,
+            "mips" | "mipsel" => Architecture::Mips,
+            "mips64" | "mips64el" => Architecture::Mips64,
+            "loongarch64" => Architecture::Loongarch64,
+            "sparc" => Architecture::Sparc,
+            "sparc64" => Architecture::Sparc64,
+            "alpha" => Architecture::Alpha,
+            "m68k" => Architecture::M68k,
+            _ => {
+                return Err(ParseArchitectureError {
+                    value: s.to_owned(),
+                })
+            }
+        })
+    }
+}
+
+/// An error returned when parsing an unknown architecture name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseArchitectureError {
+    value: String,
+}
+
+impl Display for ParseArchitectureError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "unknown architecture: '{}'", self.value)
+    }
+}
+
+impl Error for ParseArchitectureError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_str() {
+        let data = [
+            ("i386", Ok(Architecture::X86)),
+            ("i686", Ok(Architecture::X86)),
+            ("x86_64", Ok(Architecture::X86_64)),
+            ("amd64", Ok(Architecture::X86_64)),
+            ("AMD64", Ok(Architecture::X86_64)),
+            ("aarch64", Ok(Architecture::Aarch64)),
+            ("arm64", Ok(Architecture::Aarch64)),
+            ("arm", Ok(Architecture::Arm)),
+            ("armv7l", Ok(Architecture::Arm)),
+            ("earmv7hf", Ok(Architecture::Arm)),
+            ("riscv64", Ok(Architecture::Riscv64)),
+            ("ppc64le", Ok(Architecture::Ppc64le)),
+            ("ppc64el", Ok(Architecture::Ppc64le)),
+            ("s390", Ok(Architecture::S390)),
+            ("s390x", Ok(Architecture::S390x)),
+            ("sparc", Ok(Architecture::Sparc)),
+            ("alpha", Ok(Architecture::Alpha)),
+            ("m68k", Ok(Architecture::M68k)),
+            ("i86pc", Ok(Architecture::X86_64)),
+            ("mips64", Ok(Architecture::Mips64)),
+            ("loongarch64", Ok(Architecture::Loongarch64)),
+            (
+                "",
+                Err(ParseArchitectureError {
+                    value: "".to_owned(),
+                }),
+            ),
+            (
+                "unknown",
+                Err(ParseArchitectureError {
+                    value: "unknown".to_owned(),
+                }),
+            ),
+        ];
+
+        for (s, expected) in &data {
+            assert_eq!(expected, &s.parse::<Architecture>());
+        }
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let data = [
+            Architecture::X86,
+            Architecture::X86_64,
+            Architecture::Arm,
+            Architecture::Aarch64,
+            Architecture::Ia64,
+            Architecture::Riscv32,
+            Architecture::Riscv64,
+            Architecture::Ppc,
+            Architecture::Ppc64,
+            Architecture::Ppc64le,
+            Architecture::S390,
+            Architecture::S390x,
+            Architecture::Mips,
+            Architecture::Mips64,
+            Architecture::Loongarch64,
+            Architecture::Sparc,
+            Architecture::Sparc64,
+            Architecture::Alpha,
+            Architecture::M68k,
+        ];
+
+        for arch in &data {
+            assert_eq!(Ok(*arch), arch.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn bitness() {
+        assert_eq!(Bitness::X32, Architecture::X86.bitness());
+        assert_eq!(Bitness::X64, Architecture::X86_64.bitness());
+        assert_eq!(Bitness::X32, Architecture::Arm.bitness());
+        assert_eq!(Bitness::X64, Architecture::Riscv64.bitness());
+        assert_eq!(Bitness::X32, Architecture::S390.bitness());
+        assert_eq!(Bitness::X32, Architecture::Sparc.bitness());
+        assert_eq!(Bitness::X32, Architecture::M68k.bitness());
+        assert_eq!(Bitness::X64, Architecture::Alpha.bitness());
+    }
 
     #[test]
-    fn uname_nonempty() {
-        let val = get().expect("architecture::get() failed");
-        assert!(!val.is_empty());
+    fn parse_error_display() {
+        let err = "foo".parse::<Architecture>().unwrap_err();
+        assert_eq!("unknown architecture: 'foo'", err.to_string());
     }
 }
@@ -1,4 +1,4 @@
-// spell-checker:ignore getconf
+// spell-checker:ignore getconf, isainfo, prtconf
 
 use std::fmt::{self, Display, Formatter};
 #[cfg(any(
@@ -15,6 +15,24 @@ use std::fmt::{self, Display, Formatter};
 ))]
 use std::process::{Command, Output};
 
+#[cfg(any(
+    target_os = "aix",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "cygwin",
+    target_os = "hurd",
+))]
+use crate::{
+    report::{used, Attempt, Outcome, Property},
+    uname::{uname_syscall, UnameField},
+    Architecture, Detector, Source,
+};
+
 /// Operating system architecture in terms of how many bits compose the basic values it can deal with.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -39,15 +57,102 @@ impl Display for Bitness {
     }
 }
 
+/// Determines bitness natively and falls back to the platform command if `Source::Getconf` is
+/// enabled. Every lookup is recorded in `attempts`.
 #[cfg(any(
+    target_os = "aix",
     target_os = "dragonfly",
     target_os = "freebsd",
+    target_os = "illumos",
     target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
     target_os = "cygwin",
+    target_os = "hurd",
+))]
+pub fn lookup(detector: &Detector, attempts: &mut Vec<Attempt>) -> Bitness {
+    let native = get_native();
+    attempts.push(Attempt::new(
+        Property::Bitness,
+        None,
+        "native",
+        used(native.as_ref()),
+    ));
+    if let Some(bitness) = native {
+        return bitness;
+    }
+
+    if !detector.is_enabled(Source::Getconf) {
+        attempts.push(Attempt::new(
+            Property::Bitness,
+            Some(Source::Getconf),
+            COMMAND,
+            Outcome::Skipped,
+        ));
+        return Bitness::Unknown;
+    }
+
+    let bitness = get_from_command();
+    let outcome = match bitness {
+        Bitness::Unknown => Outcome::NotFound,
+        _ => Outcome::Used(bitness.to_string()),
+    };
+    attempts.push(Attempt::new(
+        Property::Bitness,
+        Some(Source::Getconf),
+        COMMAND,
+        outcome,
+    ));
+    bitness
+}
+
+/// Determines bitness without spawning any processes.
+///
+/// A 64-bit process can only run on a 64-bit system, otherwise the machine name reported by the
+/// native `uname` call is used, so 32-bit processes on a 64-bit system are handled correctly.
+#[cfg(any(
+    target_os = "aix",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "illumos",
+    target_os = "linux",
     target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "cygwin",
     target_os = "hurd",
 ))]
-pub fn get() -> Bitness {
+pub fn get_native() -> Option<Bitness> {
+    if cfg!(target_pointer_width = "64") {
+        return Some(Bitness::X64);
+    }
+
+    uname_syscall(UnameField::Machine)
+        .and_then(|machine| machine.parse::<Architecture>().ok())
+        .map(Architecture::bitness)
+}
+
+/// The command that is used when bitness can't be determined natively.
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "cygwin",
+    target_os = "macos",
+    target_os = "hurd",
+))]
+const COMMAND: &str = "getconf LONG_BIT";
+
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "linux",
+    target_os = "cygwin",
+    target_os = "macos",
+    target_os = "hurd",
+))]
+fn get_from_command() -> Bitness {
     match &Command::new("getconf").arg("LONG_BIT").output() {
         Ok(Output { stdout, .. }) if stdout == b"32\n" => Bitness::X32,
         Ok(Output { stdout, .. }) if stdout == b"64\n" => Bitness::X64,
@@ -56,7 +161,10 @@ pub fn get() -> Bitness {
 }
 
 #[cfg(target_os = "netbsd")]
-pub fn get() -> Bitness {
+const COMMAND: &str = "sysctl -n hw.machine_arch";
+
+#[cfg(target_os = "netbsd")]
+fn get_from_command() -> Bitness {
     match &Command::new("sysctl")
         .arg("-n")
         .arg("hw.machine_arch")
@@ -73,7 +181,10 @@ pub fn get() -> Bitness {
 }
 
 #[cfg(target_os = "openbsd")]
-pub fn get() -> Bitness {
+const COMMAND: &str = "sysctl -n hw.machine";
+
+#[cfg(target_os = "openbsd")]
+fn get_from_command() -> Bitness {
     match &Command::new("sysctl").arg("-n").arg("hw.machine").output() {
         Ok(Output { stdout, .. }) if stdout == b"amd64\n" => Bitness::X64,
         Ok(Output { stdout, .. }) if stdout == b"x86_64\n" => Bitness::X64,
@@ -86,7 +197,10 @@ pub fn get() -> Bitness {
 }
 
 #[cfg(target_os = "illumos")]
-pub fn get() -> Bitness {
+const COMMAND: &str = "isainfo -b";
+
+#[cfg(target_os = "illumos")]
+fn get_from_command() -> Bitness {
     match &Command::new("isainfo").arg("-b").output() {
         Ok(Output { stdout, .. }) if stdout == b"64\n" => Bitness::X64,
         Ok(Output { stdout, .. }) if stdout == b"32\n" => Bitness::X32,
@@ -95,7 +209,10 @@ pub fn get() -> Bitness {
 }
 
 #[cfg(target_os = "aix")]
-pub fn get() -> Bitness {
+const COMMAND: &str = "prtconf -c";
+
+#[cfg(target_os = "aix")]
+fn get_from_command() -> Bitness {
     match &Command::new("prtconf").arg("-c").output() {
         Ok(Output { stdout, .. }) if stdout == b"CPU Type: 64-bit\n" => Bitness::X64,
         Ok(Output { stdout, .. }) if stdout == b"CPU Type: 32-bit\n" => Bitness::X32,
@@ -122,11 +239,18 @@ mod tests {
     use pretty_assertions::assert_ne;
 
     #[test]
-    fn get_bitness() {
-        let b = get();
+    fn lookup_bitness() {
+        let b = lookup(&Detector::new(), &mut Vec::new());
         assert_ne!(b, Bitness::Unknown);
     }
 
+    #[cfg(not(any(target_os = "aix", target_os = "netbsd", target_os = "openbsd")))]
+    #[test]
+    fn get_native_bitness() {
+        let b = get_native();
+        assert_ne!(b, None);
+    }
+
     #[test]
     fn display() {
         let data = [
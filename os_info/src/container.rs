@@ -0,0 +1,62 @@
+// spell-checker:ignore nspawn
+
+use std::fmt::{self, Display, Formatter};
+
+/// Container runtime or application sandbox the current process is running in.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Container {
+    /// Docker (<https://www.docker.com/>).
+    Docker,
+    /// Podman (<https://podman.io/>).
+    Podman,
+    /// LXC (<https://linuxcontainers.org/>).
+    Lxc,
+    /// systemd-nspawn (<https://www.freedesktop.org/software/systemd/man/latest/systemd-nspawn.html>).
+    SystemdNspawn,
+    /// Kubernetes pod (<https://kubernetes.io/>).
+    Kubernetes,
+    /// Flatpak sandbox (<https://flatpak.org/>).
+    Flatpak,
+    /// Snap confinement (<https://snapcraft.io/>).
+    Snap,
+    /// A container that was detected, but whose runtime is unknown.
+    Unknown,
+}
+
+impl Display for Container {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Container::Lxc => write!(f, "LXC"),
+            Container::SystemdNspawn => write!(f, "systemd-nspawn"),
+            Container::Unknown => write!(f, "unknown container"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display() {
+        let data = [
+            (Container::Docker, "Docker"),
+            (Container::Podman, "Podman"),
+            (Container::Lxc, "LXC"),
+            (Container::SystemdNspawn, "systemd-nspawn"),
+            (Container::Kubernetes, "Kubernetes"),
+            (Container::Flatpak, "Flatpak"),
+            (Container::Snap, "Snap"),
+            (Container::Unknown, "unknown container"),
+        ];
+
+        for (container, expected) in &data {
+            assert_eq!(&container.to_string(), expected);
+        }
+    }
+}
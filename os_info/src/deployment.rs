@@ -0,0 +1,55 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Deployment model of an immutable (image based) operating system, where the system files can't
+/// be modified by installing packages in place.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Deployment {
+    /// OSTree based system, such as Fedora Silverblue, Kinoite, Bluefin or Bazzite
+    /// (<https://ostreedev.github.io/ostree/>).
+    Ostree,
+    /// System updated with snapshots by `transactional-update`, such as openSUSE MicroOS
+    /// (<https://en.opensuse.org/Portal:MicroOS>).
+    Transactional,
+    /// NixOS that is built from a declarative configuration (<https://nixos.org/>).
+    Nix,
+    /// Ubuntu Core that is composed of snaps (<https://ubuntu.com/core>).
+    UbuntuCore,
+    /// System with the read-only `/usr` directory.
+    ReadOnly,
+}
+
+impl Display for Deployment {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Deployment::Ostree => write!(f, "OSTree"),
+            Deployment::Transactional => write!(f, "transactional-update"),
+            Deployment::UbuntuCore => write!(f, "Ubuntu Core"),
+            Deployment::ReadOnly => write!(f, "read-only"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display() {
+        let data = [
+            (Deployment::Ostree, "OSTree"),
+            (Deployment::Transactional, "transactional-update"),
+            (Deployment::Nix, "Nix"),
+            (Deployment::UbuntuCore, "Ubuntu Core"),
+            (Deployment::ReadOnly, "read-only"),
+        ];
+
+        for (deployment, expected) in &data {
+            assert_eq!(*expected, deployment.to_string());
+        }
+    }
+}
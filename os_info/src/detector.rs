@@ -0,0 +1,437 @@
+// spell-checker:ignore getconf
+
+use std::fmt::{self, Display, Formatter};
+#[cfg(target_os = "linux")]
+use std::path::{Path, PathBuf};
+
+use crate::{Container, DetectionReport, Info, Virtualization};
+
+/// A source of the operating system information that can be used by `Detector`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Source {
+    /// Output of the `lsb_release -a` command.
+    LsbRelease,
+    /// The `os-release` file.
+    OsRelease,
+    /// Distribution specific release files such as `/etc/redhat-release`.
+    ReleaseFiles,
+    /// The `uname` command that is used when the native `uname` call fails.
+    UnameCommand,
+    /// The `getconf LONG_BIT` command that is used when bitness can't be determined natively. The
+    /// platforms without `getconf` use their own command instead, such as `isainfo -b` on illumos.
+    Getconf,
+}
+
+impl Source {
+    /// Returns `true` if using this source requires spawning a process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Source;
+    ///
+    /// assert!(Source::LsbRelease.spawns_process());
+    /// assert!(!Source::OsRelease.spawns_process());
+    /// ```
+    pub fn spawns_process(self) -> bool {
+        match self {
+            Source::LsbRelease | Source::UnameCommand | Source::Getconf => true,
+            Source::OsRelease | Source::ReleaseFiles => false,
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Source::LsbRelease => write!(f, "lsb_release"),
+            Source::OsRelease => write!(f, "os-release"),
+            Source::ReleaseFiles => write!(f, "release files"),
+            Source::UnameCommand => write!(f, "uname command"),
+            Source::Getconf => write!(f, "getconf"),
+        }
+    }
+}
+
+/// Configurable operating system detection.
+///
+/// By default all sources are enabled and the result is the same as returned by `os_info::get`.
+/// The distribution sources only affect Linux, while the `uname` and `getconf` sources apply to
+/// every platform that uses them. Windows and the other platforms without `uname` ignore this
+/// configuration.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::{Detector, Source};
+///
+/// // Detect the distribution without spawning any processes, preferring the os-release file.
+/// let info = Detector::new()
+///     .sources(&[Source::OsRelease, Source::ReleaseFiles, Source::LsbRelease])
+///     .spawn_processes(false)
+///     .detect();
+/// println!("OS information: {info}");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detector {
+    #[cfg(target_os = "linux")]
+    root: PathBuf,
+    sources: Vec<Source>,
+    spawn_processes: bool,
+}
+
+impl Detector {
+    /// Constructs a new `Detector` instance with all sources enabled in the default order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Source};
+    ///
+    /// let detector = Detector::new();
+    /// assert!(detector.is_enabled(Source::LsbRelease));
+    /// assert!(detector.is_enabled(Source::Getconf));
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            #[cfg(target_os = "linux")]
+            root: PathBuf::from("/"),
+            sources: vec![
+                Source::LsbRelease,
+                Source::OsRelease,
+                Source::ReleaseFiles,
+                Source::UnameCommand,
+                Source::Getconf,
+            ],
+            spawn_processes: true,
+        }
+    }
+
+    /// Sets the root directory that is used to look up the release files.
+    ///
+    /// When the root isn't `/`, no processes are spawned and the bitness and architecture aren't
+    /// determined because they describe the running system and not the one under `root`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// let info = Detector::new().root("/mnt/image").detect();
+    /// println!("Distribution: {}", info.os_type());
+    /// ```
+    #[cfg(target_os = "linux")]
+    pub fn root<P: Into<PathBuf>>(mut self, root: P) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    /// Sets the enabled sources. The distribution is determined by the first source in the given
+    /// order that recognizes it, other sources are disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Source};
+    ///
+    /// let detector = Detector::new().sources(&[Source::ReleaseFiles, Source::OsRelease]);
+    /// assert!(!detector.is_enabled(Source::LsbRelease));
+    /// assert!(detector.is_enabled(Source::OsRelease));
+    /// ```
+    pub fn sources(mut self, sources: &[Source]) -> Self {
+        self.sources.clear();
+        for &source in sources {
+            self = self.enable(source);
+        }
+        self
+    }
+
+    /// Enables the given source by adding it to the end of the sources list if it isn't there yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Source};
+    ///
+    /// let detector = Detector::new().sources(&[]).enable(Source::OsRelease);
+    /// assert!(detector.is_enabled(Source::OsRelease));
+    /// ```
+    pub fn enable(mut self, source: Source) -> Self {
+        if !self.sources.contains(&source) {
+            self.sources.push(source);
+        }
+        self
+    }
+
+    /// Disables the given source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Source};
+    ///
+    /// let detector = Detector::new().disable(Source::LsbRelease);
+    /// assert!(!detector.is_enabled(Source::LsbRelease));
+    /// ```
+    pub fn disable(mut self, source: Source) -> Self {
+        self.sources.retain(|&s| s != source);
+        self
+    }
+
+    /// Sets whether spawning processes is allowed. If it isn't, then all sources that require
+    /// running a command are disabled.
+    ///
+    /// The `uname` and `getconf` commands are only used when the native system calls fail, so
+    /// disabling them doesn't affect the result on most systems. Platform specific commands that
+    /// have no native alternative in this crate, such as `sw_vers` on macOS or `sysctl` on
+    /// FreeBSD, are still run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Source};
+    ///
+    /// let detector = Detector::new().spawn_processes(false);
+    /// assert!(!detector.is_enabled(Source::LsbRelease));
+    /// assert!(detector.is_enabled(Source::OsRelease));
+    /// ```
+    pub fn spawn_processes(mut self, allowed: bool) -> Self {
+        self.spawn_processes = allowed;
+        self
+    }
+
+    /// Returns `true` if the given source is going to be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Source};
+    ///
+    /// let detector = Detector::new();
+    /// assert!(detector.is_enabled(Source::OsRelease));
+    /// ```
+    pub fn is_enabled(&self, source: Source) -> bool {
+        self.enabled_sources().any(|s| s == source)
+    }
+
+    /// Returns information about the operating system using the configured sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// let info = Detector::new().detect();
+    /// println!("OS information: {info}");
+    /// ```
+    pub fn detect(&self) -> Info {
+        self.explain().into_info()
+    }
+
+    /// Detects the operating system the same way as `detect`, but also records every lookup that
+    /// was made, what it returned and which result was used. See `DetectionReport` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Property};
+    ///
+    /// let report = Detector::new().explain();
+    /// if let Some(attempt) = report.winner(Property::Distribution) {
+    ///     println!("{} is detected from {}", report.info().os_type(), attempt.target());
+    /// }
+    /// ```
+    pub fn explain(&self) -> DetectionReport {
+        #[cfg(any(
+            target_os = "aix",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "illumos",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "cygwin",
+            target_os = "hurd",
+        ))]
+        {
+            crate::imp::explain(self)
+        }
+        #[cfg(not(any(
+            target_os = "aix",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "illumos",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "cygwin",
+            target_os = "hurd",
+        )))]
+        {
+            DetectionReport {
+                info: crate::imp::current_platform(),
+                attempts: Vec::new(),
+            }
+        }
+    }
+
+    /// Returns the container runtime or application sandbox, looking up the marker files under the
+    /// configured root. Containers are only detected on Linux, other platforms always return
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// if let Some(container) = Detector::new().container() {
+    ///     println!("Running in {container}");
+    /// }
+    /// ```
+    pub fn container(&self) -> Option<Container> {
+        #[cfg(target_os = "linux")]
+        {
+            crate::imp::container(self)
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+
+    /// Returns the hypervisor, reading the DMI data and CPU flags under the configured root.
+    /// Virtualization is only detected on Linux, other platforms always return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// if let Some(virtualization) = Detector::new().virtualization() {
+    ///     println!("Virtual machine: {}", virtualization.is_virtual());
+    /// }
+    /// ```
+    pub fn virtualization(&self) -> Option<Virtualization> {
+        #[cfg(target_os = "linux")]
+        {
+            crate::imp::virtualization(self)
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+
+    /// Returns the enabled sources in the order they should be used.
+    pub(crate) fn enabled_sources(&self) -> impl Iterator<Item = Source> + '_ {
+        let spawn_processes = self.spawn_processes;
+        self.sources
+            .iter()
+            .copied()
+            .filter(move |s| spawn_processes || !s.spawns_process())
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(crate) fn root_path(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl Default for Detector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn default() {
+        assert_eq!(Detector::new(), Detector::default());
+    }
+
+    #[test]
+    fn all_sources_enabled() {
+        let detector = Detector::new();
+        assert_eq!(
+            vec![
+                Source::LsbRelease,
+                Source::OsRelease,
+                Source::ReleaseFiles,
+                Source::UnameCommand,
+                Source::Getconf,
+            ],
+            detector.enabled_sources().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn sources_order() {
+        let detector = Detector::new().sources(&[
+            Source::ReleaseFiles,
+            Source::OsRelease,
+            Source::ReleaseFiles,
+        ]);
+        assert_eq!(
+            vec![Source::ReleaseFiles, Source::OsRelease],
+            detector.enabled_sources().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn enable_disable() {
+        let detector = Detector::new()
+            .disable(Source::LsbRelease)
+            .disable(Source::Getconf)
+            .enable(Source::LsbRelease);
+        assert_eq!(
+            vec![
+                Source::OsRelease,
+                Source::ReleaseFiles,
+                Source::UnameCommand,
+                Source::LsbRelease,
+            ],
+            detector.enabled_sources().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn source_display() {
+        let data = [
+            (Source::LsbRelease, "lsb_release"),
+            (Source::OsRelease, "os-release"),
+            (Source::ReleaseFiles, "release files"),
+            (Source::UnameCommand, "uname command"),
+            (Source::Getconf, "getconf"),
+        ];
+
+        for (source, expected) in &data {
+            assert_eq!(*expected, source.to_string());
+        }
+    }
+
+    #[test]
+    fn no_processes() {
+        let detector = Detector::new().spawn_processes(false);
+        assert_eq!(
+            vec![Source::OsRelease, Source::ReleaseFiles],
+            detector.enabled_sources().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn no_processes_skips_commands() {
+        let report = Detector::new().spawn_processes(false).explain();
+        for attempt in report.attempts() {
+            if attempt.source().map_or(false, Source::spawns_process) {
+                assert_eq!(&crate::Outcome::Skipped, attempt.outcome(), "{attempt:?}");
+            }
+        }
+    }
+}
@@ -4,26 +4,45 @@ use log::trace;
 
 use crate::{
     bitness,
-    uname::{uname, UnameField},
-    Bitness, Info, Type, Version,
+    report::Property,
+    uname::{self, UnameField},
+    DetectionReport, Detector, Info, KernelVersion, Type, Version,
 };
 
 pub fn current_platform() -> Info {
     trace!("dragonfly::current_platform is called");
+    explain(&Detector::new()).into_info()
+}
+
+pub fn explain(detector: &Detector) -> DetectionReport {
+    trace!("dragonfly::explain is called with {:?}", detector);
+    let mut attempts = Vec::new();
 
-    let version = uname(UnameField::Release)
-        .map(Version::from_string)
-        .unwrap_or_else(|| Version::Unknown);
+    let version = uname::lookup(
+        UnameField::Release,
+        Property::Distribution,
+        detector,
+        &mut attempts,
+    )
+    .map(Version::from_string)
+    .unwrap_or_else(|| Version::Unknown);
 
     let info = Info {
         os_type: Type::DragonFly,
         version,
-        bitness: bitness::get(),
+        bitness: bitness::lookup(detector, &mut attempts),
+        kernel_version: uname::lookup(
+            UnameField::Release,
+            Property::KernelVersion,
+            detector,
+            &mut attempts,
+        )
+        .map(KernelVersion::parse),
         ..Default::default()
     };
 
     trace!("Returning {:?}", info);
-    info
+    DetectionReport { info, attempts }
 }
 
 #[cfg(test)]
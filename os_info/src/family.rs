@@ -0,0 +1,67 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A family of operating systems sharing the same origin, package format and conventions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Family {
+    /// Debian and its derivatives, such as Ubuntu, Linux Mint or Kali Linux.
+    Debian,
+    /// Red Hat Enterprise Linux, Fedora and their derivatives, such as Rocky Linux or AlmaLinux.
+    RedHat,
+    /// Arch Linux and its derivatives, such as Manjaro or EndeavourOS.
+    Arch,
+    /// SUSE Linux Enterprise and openSUSE.
+    Suse,
+    /// Gentoo Linux.
+    Gentoo,
+    /// Alpine Linux and the distributions built the same way, such as Alpaquita Linux.
+    Alpine,
+    /// BSD descendants, such as FreeBSD, OpenBSD or NetBSD.
+    Bsd,
+    /// Apple operating systems (macOS and iOS).
+    Apple,
+    /// Microsoft Windows.
+    Windows,
+    /// Independent Linux distributions and other operating systems.
+    Other,
+}
+
+impl Display for Family {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Family::RedHat => write!(f, "Red Hat"),
+            Family::Suse => write!(f, "SUSE"),
+            Family::Bsd => write!(f, "BSD"),
+            Family::Other => write!(f, "other"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display() {
+        let data = [
+            (Family::Debian, "Debian"),
+            (Family::RedHat, "Red Hat"),
+            (Family::Arch, "Arch"),
+            (Family::Suse, "SUSE"),
+            (Family::Gentoo, "Gentoo"),
+            (Family::Alpine, "Alpine"),
+            (Family::Bsd, "BSD"),
+            (Family::Apple, "Apple"),
+            (Family::Windows, "Windows"),
+            (Family::Other, "other"),
+        ];
+
+        for (family, expected) in &data {
+            assert_eq!(*expected, family.to_string());
+        }
+    }
+}
@@ -1,28 +1,52 @@
 use log::trace;
 
 use crate::{
-    architecture, bitness,
-    uname::{uname, UnameField},
-    Info, Type, Version,
+    bitness,
+    report::Property,
+    uname::{self, UnameField},
+    DetectionReport, Detector, Info, KernelVersion, Type, Version,
 };
 
 pub fn current_platform() -> Info {
     trace!("hurd::current_platform is called");
+    explain(&Detector::new()).into_info()
+}
+
+pub fn explain(detector: &Detector) -> DetectionReport {
+    trace!("hurd::explain is called with {:?}", detector);
+    let mut attempts = Vec::new();
 
-    let version = uname(UnameField::Release)
-        .map(Version::from_string)
-        .unwrap_or_else(|| Version::Unknown);
+    let version = uname::lookup(
+        UnameField::Release,
+        Property::Distribution,
+        detector,
+        &mut attempts,
+    )
+    .map(Version::from_string)
+    .unwrap_or_else(|| Version::Unknown);
 
     let info = Info {
         os_type: Type::Hurd,
         version,
-        bitness: bitness::get(),
-        architecture: architecture::get(),
+        bitness: bitness::lookup(detector, &mut attempts),
+        architecture: uname::lookup(
+            UnameField::Machine,
+            Property::Architecture,
+            detector,
+            &mut attempts,
+        ),
+        kernel_version: uname::lookup(
+            UnameField::Release,
+            Property::KernelVersion,
+            detector,
+            &mut attempts,
+        )
+        .map(KernelVersion::parse),
         ..Default::default()
     };
 
     trace!("Returning {:?}", info);
-    info
+    DetectionReport { info, attempts }
 }
 
 #[cfg(test)]
@@ -5,30 +5,56 @@ use log::{error, trace};
 
 use crate::{
     bitness,
-    uname::{uname, UnameField},
-    Info, Type, Version,
+    report::{Attempt, Property},
+    uname::{self, UnameField},
+    DetectionReport, Detector, Info, KernelVersion, Type, Version,
 };
 
 pub fn current_platform() -> Info {
     trace!("illumos::current_platform is called");
+    explain(&Detector::new()).into_info()
+}
+
+pub fn explain(detector: &Detector) -> DetectionReport {
+    trace!("illumos::explain is called with {:?}", detector);
+    let mut attempts = Vec::new();
 
-    let version = uname(UnameField::Version)
-        .map(Version::from_string)
-        .unwrap_or_else(|| Version::Unknown);
+    let version = uname::lookup(
+        UnameField::Version,
+        Property::Distribution,
+        detector,
+        &mut attempts,
+    )
+    .map(Version::from_string)
+    .unwrap_or_else(|| Version::Unknown);
 
     let info = Info {
-        os_type: get_os(),
+        os_type: get_os(detector, &mut attempts),
         version,
-        bitness: bitness::get(),
+        bitness: bitness::lookup(detector, &mut attempts),
+        kernel_version: uname::lookup(
+            UnameField::Release,
+            Property::KernelVersion,
+            detector,
+            &mut attempts,
+        )
+        .map(KernelVersion::parse),
         ..Default::default()
     };
 
     trace!("Returning {:?}", info);
-    info
+    DetectionReport { info, attempts }
 }
 
-fn get_os() -> Type {
-    match uname(UnameField::OperatingSystem).as_deref() {
+fn get_os(detector: &Detector, attempts: &mut Vec<Attempt>) -> Type {
+    match uname::lookup(
+        UnameField::OperatingSystem,
+        Property::Distribution,
+        detector,
+        attempts,
+    )
+    .as_deref()
+    {
         Some("illumos") => Type::Illumos,
         _ => Type::Unknown,
     }
@@ -2,7 +2,12 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use super::{Bitness, Type, Version};
+#[cfg(target_os = "linux")]
+use super::linux::OsRelease;
+use super::{
+    lifecycle, package_manager, release_name, Architecture, Bitness, Date, Deployment,
+    KernelVersion, Lifecycle, PackageManager, SupportStatus, Type, Version, WslVersion,
+};
 
 /// Holds information about operating system (type, version, etc.).
 ///
@@ -29,11 +34,24 @@ pub struct Info {
     pub(crate) edition: Option<String>,
     /// Operating system codename.
     pub(crate) codename: Option<String>,
+    /// The closest known operating system this one is derived from.
+    pub(crate) based_on: Option<Type>,
     /// Operating system architecture in terms of how many bits compose the basic values it can deal
     /// with. See `Bitness` for details.
     pub(crate) bitness: Bitness,
     /// Processor architecture.
     pub(crate) architecture: Option<String>,
+    /// Operating system kernel version. See `KernelVersion` for details.
+    pub(crate) kernel_version: Option<KernelVersion>,
+    /// Windows Subsystem for Linux version if the system runs under it.
+    pub(crate) wsl: Option<WslVersion>,
+    /// Package managers found on the system, the preferred one first.
+    pub(crate) package_managers: Vec<PackageManager>,
+    /// Deployment model if the operating system is immutable.
+    pub(crate) deployment: Option<Deployment>,
+    /// Parsed contents of the os-release file.
+    #[cfg(target_os = "linux")]
+    pub(crate) os_release: Option<OsRelease>,
 }
 
 impl Info {
@@ -58,8 +76,15 @@ impl Info {
             version: Version::Unknown,
             edition: None,
             codename: None,
+            based_on: None,
             bitness: Bitness::Unknown,
             architecture: None,
+            kernel_version: None,
+            wsl: None,
+            package_managers: Vec::new(),
+            deployment: None,
+            #[cfg(target_os = "linux")]
+            os_release: None,
         }
     }
 
@@ -140,6 +165,43 @@ impl Info {
         self.codename.as_ref().map(String::as_ref)
     }
 
+    /// Returns the marketing name of the release, such as `Sonoma` for macOS 14, `Noble Numbat`
+    /// for Ubuntu 24.04 or `23H2` for Windows build 22631.
+    ///
+    /// The name is looked up in the table embedded in the crate, falling back to the codename
+    /// reported by the system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = os_info::get();
+    /// if let Some(name) = info.release_name() {
+    ///     println!("{} {name}", info.os_type());
+    /// }
+    /// ```
+    pub fn release_name(&self) -> Option<&str> {
+        release_name::find(self.os_type, &self.version).or_else(|| self.codename())
+    }
+
+    /// Returns the closest known operating system this one is derived from.
+    ///
+    /// On Linux this is determined from the `ID_LIKE` field of the os-release file, so for example
+    /// Ubuntu is based on Debian. Distributions that aren't known by this library are reported as
+    /// `Type::Linux` with this field set, while their original `ID` is available through the
+    /// parsed os-release file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Info;
+    ///
+    /// let info = Info::unknown();
+    /// assert_eq!(None, info.based_on());
+    /// ```
+    pub fn based_on(&self) -> Option<Type> {
+        self.based_on
+    }
+
     /// Returns operating system bitness. See `Bitness` for details.
     ///
     /// # Examples
@@ -166,6 +228,179 @@ impl Info {
     pub fn architecture(&self) -> Option<&str> {
         self.architecture.as_ref().map(String::as_ref)
     }
+
+    /// Returns the normalized processor architecture if it is known. See `Architecture` for
+    /// details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Info;
+    ///
+    /// let info = Info::unknown();
+    /// assert_eq!(None, info.normalized_architecture());
+    /// ```
+    pub fn normalized_architecture(&self) -> Option<Architecture> {
+        self.architecture.as_ref().and_then(|a| a.parse().ok())
+    }
+
+    /// Returns operating system kernel version. See `KernelVersion` for details.
+    ///
+    /// On Windows it contains the major and minor versions along with the build number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Info;
+    ///
+    /// let info = Info::unknown();
+    /// assert_eq!(None, info.kernel_version());
+    /// ```
+    pub fn kernel_version(&self) -> Option<&KernelVersion> {
+        self.kernel_version.as_ref()
+    }
+
+    /// Returns the Windows Subsystem for Linux version if the system runs under it. See
+    /// `WslVersion` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Info;
+    ///
+    /// let info = Info::unknown();
+    /// assert_eq!(None, info.wsl());
+    /// ```
+    pub fn wsl(&self) -> Option<WslVersion> {
+        self.wsl
+    }
+
+    /// Returns the system package managers, the preferred one first.
+    ///
+    /// On Linux the package managers are determined by the executables installed on the system,
+    /// so for example YUM is returned instead of DNF on CentOS 7. On other platforms or if no
+    /// package manager is found, they are inferred from the operating system type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Info, PackageManager, Type};
+    ///
+    /// let info = Info::with_type(Type::Fedora);
+    /// assert_eq!(
+    ///     &[PackageManager::Dnf, PackageManager::Yum],
+    ///     info.package_managers()
+    /// );
+    /// ```
+    pub fn package_managers(&self) -> &[PackageManager] {
+        if self.package_managers.is_empty() {
+            package_manager::candidates(self.based_on.unwrap_or(self.os_type))
+        } else {
+            &self.package_managers
+        }
+    }
+
+    /// Returns the deployment model of the immutable operating system. See `Deployment` for
+    /// details.
+    ///
+    /// `None` is returned for the traditional systems where packages are installed in place and
+    /// on the platforms other than Linux.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Info;
+    ///
+    /// let info = Info::unknown();
+    /// assert_eq!(None, info.deployment());
+    /// ```
+    pub fn deployment(&self) -> Option<Deployment> {
+        self.deployment
+    }
+
+    /// Returns `true` if the operating system is immutable (image based), so the packages can't
+    /// be installed with the system package manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = os_info::get();
+    /// if info.is_immutable() {
+    ///     println!("Use Flatpak or a container to install the software");
+    /// }
+    /// ```
+    pub fn is_immutable(&self) -> bool {
+        self.deployment.is_some()
+    }
+
+    /// Returns the lifecycle dates of the operating system release from the table embedded in
+    /// the crate. See `Lifecycle` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Info, Type};
+    ///
+    /// let info = Info::with_type(Type::Ubuntu);
+    /// assert_eq!(None, info.lifecycle());
+    /// ```
+    pub fn lifecycle(&self) -> Option<&'static Lifecycle> {
+        lifecycle::find(self.os_type, &self.version, self.edition())
+    }
+
+    /// Returns the support status of the operating system release on the given date, or `None`
+    /// if the release lifecycle is unknown.
+    ///
+    /// The `SUPPORT_END` date from the os-release file takes precedence over the end of the
+    /// regular support from the embedded lifecycle table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Date, SupportStatus};
+    ///
+    /// let info = os_info::get();
+    /// if info.support_status(Date::today()) == Some(SupportStatus::EndOfLife) {
+    ///     println!("{info} doesn't receive updates anymore");
+    /// }
+    /// ```
+    pub fn support_status(&self, today: Date) -> Option<SupportStatus> {
+        let support_end = self.support_end();
+        match self.lifecycle() {
+            Some(l) => Some(lifecycle::status(
+                today,
+                support_end.or_else(|| l.end_of_life()),
+                l.extended_support_end(),
+            )),
+            None => support_end.map(|end| lifecycle::status(today, Some(end), None)),
+        }
+    }
+
+    fn support_end(&self) -> Option<Date> {
+        #[cfg(target_os = "linux")]
+        {
+            self.os_release.as_ref()?.support_end()?.parse().ok()
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+
+    /// Returns the parsed contents of the os-release file if it is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Info;
+    ///
+    /// let info = Info::unknown();
+    /// assert_eq!(None, info.os_release());
+    /// ```
+    #[cfg(target_os = "linux")]
+    pub fn os_release(&self) -> Option<&OsRelease> {
+        self.os_release.as_ref()
+    }
 }
 
 impl Default for Info {
@@ -202,8 +437,11 @@ mod tests {
         assert_eq!(&Version::Unknown, info.version());
         assert_eq!(None, info.edition());
         assert_eq!(None, info.codename());
+        assert_eq!(None, info.based_on());
         assert_eq!(Bitness::Unknown, info.bitness());
         assert_eq!(None, info.architecture());
+        assert_eq!(None, info.kernel_version());
+        assert_eq!(None, info.wsl());
     }
 
     #[test]
@@ -260,11 +498,107 @@ mod tests {
         }
     }
 
+    #[test]
+    fn release_name() {
+        let data = [
+            (Type::Macos, "14.5.0", None, Some("Sonoma")),
+            (Type::Ubuntu, "24.04", Some("noble"), Some("Noble Numbat")),
+            (Type::Debian, "12", None, Some("bookworm")),
+            (Type::Debian, "trixie/sid", Some("trixie"), Some("trixie")),
+            (Type::Windows, "10.0.22631", None, Some("23H2")),
+            (Type::Fedora, "40", None, None),
+        ];
+
+        for (os_type, version, codename, expected) in &data {
+            let info = Info {
+                os_type: *os_type,
+                version: Version::from_string(*version),
+                codename: codename.map(str::to_owned),
+                ..Default::default()
+            };
+            assert_eq!(*expected, info.release_name(), "{info}");
+        }
+    }
+
+    #[test]
+    fn support_status() {
+        let today = "2026-10-18".parse().unwrap();
+        let data = [
+            (Type::Ubuntu, "24.04", Some(SupportStatus::Supported)),
+            (Type::Ubuntu, "20.04", Some(SupportStatus::Extended)),
+            (Type::Debian, "10", Some(SupportStatus::EndOfLife)),
+            (Type::Ubuntu, "18.10", None),
+            (Type::Arch, "", None),
+        ];
+
+        for (os_type, version, expected) in &data {
+            let info = Info {
+                os_type: *os_type,
+                version: Version::from_string(*version),
+                ..Default::default()
+            };
+            assert_eq!(*expected, info.support_status(today), "{info}");
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn support_status_support_end() {
+        let today = "2026-10-18".parse().unwrap();
+        let info = |os_type, version: &str, os_release: &str| Info {
+            os_type,
+            version: Version::from_string(version),
+            os_release: Some(OsRelease::parse(os_release)),
+            ..Default::default()
+        };
+
+        let data = [
+            (
+                info(Type::Fedora, "45", "SUPPORT_END=2027-06-01"),
+                Some(SupportStatus::Supported),
+            ),
+            (
+                info(Type::Fedora, "41", "SUPPORT_END=2026-06-01"),
+                Some(SupportStatus::EndOfLife),
+            ),
+            (
+                info(Type::Ubuntu, "22.04", "SUPPORT_END=2026-01-01"),
+                Some(SupportStatus::Extended),
+            ),
+            (
+                info(Type::Ubuntu, "22.04", "SUPPORT_END=invalid"),
+                Some(SupportStatus::Supported),
+            ),
+        ];
+
+        for (info, expected) in &data {
+            assert_eq!(*expected, info.support_status(today), "{info}");
+        }
+    }
+
     #[test]
     fn default() {
         assert_eq!(Info::default(), Info::unknown());
     }
 
+    #[test]
+    fn normalized_architecture() {
+        let data = [
+            (None, None),
+            (Some("amd64"), Some(Architecture::X86_64)),
+            (Some("arm64"), Some(Architecture::Aarch64)),
+            (Some("unknown"), None),
+        ];
+
+        for (architecture, expected) in &data {
+            let info = Info {
+                architecture: architecture.map(str::to_owned),
+                ..Default::default()
+            };
+            assert_eq!(*expected, info.normalized_architecture());
+        }
+    }
+
     #[test]
     fn display() {
         let data = [
@@ -349,8 +683,15 @@ mod tests {
                     version: Version::Semantic(10, 2, 0),
                     edition: Some("edition".to_owned()),
                     codename: Some("codename".to_owned()),
+                    based_on: Some(Type::Linux),
                     bitness: Bitness::X64,
                     architecture: Some("architecture".to_owned()),
+                    kernel_version: Some(KernelVersion::parse("1.2.3-suffix")),
+                    wsl: Some(WslVersion::Wsl2),
+                    package_managers: vec![PackageManager::Apt],
+                    deployment: Some(Deployment::Ostree),
+                    #[cfg(target_os = "linux")]
+                    os_release: None,
                 },
                 "Mac OS 10.2.0 (edition) (codename) [64-bit]",
             ),
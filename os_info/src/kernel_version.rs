@@ -0,0 +1,222 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::Version;
+
+/// Operating system kernel version.
+///
+/// The kernel release string is split into the numeric version and the distribution specific
+/// suffix, for example `6.8.0-45-generic` is parsed as `6.8.0` and `-45-generic`.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::{KernelVersion, Version};
+///
+/// let kernel = KernelVersion::parse("5.14.0-427.13.1.el9_4.x86_64");
+/// assert_eq!(&Version::Semantic(5, 14, 0), kernel.version());
+/// assert_eq!(Some("-427.13.1.el9_4.x86_64"), kernel.suffix());
+/// assert_eq!("5.14.0-427.13.1.el9_4.x86_64", kernel.release());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct KernelVersion {
+    /// Kernel release string as reported by the operating system.
+    release: String,
+    /// The numeric part of the release.
+    version: Version,
+    /// Everything after the numeric part of the release.
+    suffix: Option<String>,
+}
+
+impl KernelVersion {
+    /// Parses the kernel release string (as returned by `uname -r`).
+    ///
+    /// Up to three leading numeric components separated by dots are considered to be the version.
+    /// If the release doesn't start with a number, then the version is `Version::Custom`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{KernelVersion, Version};
+    ///
+    /// let kernel = KernelVersion::parse("14.0-RELEASE-p3");
+    /// assert_eq!(&Version::Semantic(14, 0, 0), kernel.version());
+    /// assert_eq!(Some("-RELEASE-p3"), kernel.suffix());
+    /// ```
+    pub fn parse<S: Into<String>>(release: S) -> Self {
+        let release = release.into();
+        let trimmed = release.trim();
+
+        let mut end = 0;
+        for (i, component) in trimmed.split('.').enumerate().take(3) {
+            let digits = component
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(component.len());
+            if digits == 0 {
+                break;
+            }
+            end += digits + if i > 0 { 1 } else { 0 };
+            if digits != component.len() {
+                break;
+            }
+        }
+
+        let (version, suffix) = if end == 0 {
+            (Version::from_string(trimmed), None)
+        } else {
+            let suffix = &trimmed[end..];
+            (
+                Version::from_string(&trimmed[..end]),
+                Some(suffix.to_owned()).filter(|s| !s.is_empty()),
+            )
+        };
+
+        Self {
+            version,
+            suffix,
+            release,
+        }
+    }
+
+    /// Returns the full kernel release string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::KernelVersion;
+    ///
+    /// let kernel = KernelVersion::parse("6.8.0-45-generic");
+    /// assert_eq!("6.8.0-45-generic", kernel.release());
+    /// ```
+    pub fn release(&self) -> &str {
+        &self.release
+    }
+
+    /// Returns the numeric part of the kernel release. See `Version` for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{KernelVersion, Version};
+    ///
+    /// let kernel = KernelVersion::parse("6.8.0-45-generic");
+    /// assert_eq!(&Version::Semantic(6, 8, 0), kernel.version());
+    /// ```
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns the distribution specific suffix of the kernel release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::KernelVersion;
+    ///
+    /// let kernel = KernelVersion::parse("6.8.0-45-generic");
+    /// assert_eq!(Some("-45-generic"), kernel.suffix());
+    /// ```
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+}
+
+/// Kernel versions are ordered by the numeric version first, so `6.10` is greater than `6.9`,
+/// and then by the suffix.
+impl Ord for KernelVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.version
+            .cmp(&other.version)
+            .then_with(|| self.suffix.cmp(&other.suffix))
+            .then_with(|| self.release.cmp(&other.release))
+    }
+}
+
+impl PartialOrd for KernelVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for KernelVersion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.release)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse() {
+        let data = [
+            (
+                "6.8.0-45-generic",
+                Version::Semantic(6, 8, 0),
+                Some("-45-generic"),
+            ),
+            (
+                "5.14.0-427.13.1.el9_4.x86_64",
+                Version::Semantic(5, 14, 0),
+                Some("-427.13.1.el9_4.x86_64"),
+            ),
+            (
+                "5.15.153.1-microsoft-standard-WSL2",
+                Version::Semantic(5, 15, 153),
+                Some(".1-microsoft-standard-WSL2"),
+            ),
+            ("4.19.112+", Version::Semantic(4, 19, 112), Some("+")),
+            (
+                "6.10.3-arch1-1",
+                Version::Semantic(6, 10, 3),
+                Some("-arch1-1"),
+            ),
+            (
+                "14.0-RELEASE-p3",
+                Version::Semantic(14, 0, 0),
+                Some("-RELEASE-p3"),
+            ),
+            ("23.4.0", Version::Semantic(23, 4, 0), None),
+            ("10.0.22631", Version::Semantic(10, 0, 22631), None),
+            ("7.5", Version::Semantic(7, 5, 0), None),
+            ("", Version::Unknown, None),
+            ("custom", Version::Custom("custom".to_owned()), None),
+        ];
+
+        for (release, version, suffix) in &data {
+            let kernel = KernelVersion::parse(*release);
+            assert_eq!(*release, kernel.release());
+            assert_eq!(version, kernel.version(), "release: {release}");
+            assert_eq!(*suffix, kernel.suffix(), "release: {release}");
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let data = [
+            ("6.9.0", "6.10.0", Ordering::Less),
+            ("9.0", "10.0", Ordering::Less),
+            ("6.8.0-45-generic", "6.8.0-50-generic", Ordering::Less),
+            ("6.8.0", "6.8.0-45-generic", Ordering::Less),
+            ("6.10.2-arch1-1", "6.9.12-arch1-1", Ordering::Greater),
+            ("5.15.0", "5.15.0", Ordering::Equal),
+        ];
+
+        for (a, b, expected) in &data {
+            let (a, b) = (KernelVersion::parse(*a), KernelVersion::parse(*b));
+            assert_eq!(*expected, a.cmp(&b), "{a} {b}");
+        }
+    }
+
+    #[test]
+    fn display() {
+        let kernel = KernelVersion::parse("6.8.0-45-generic");
+        assert_eq!("6.8.0-45-generic", kernel.to_string());
+    }
+}
@@ -90,20 +90,22 @@ mod imp;
 #[path = "unknown/mod.rs"]
 mod imp;
 
-#[cfg(any(
-    target_os = "linux",
-    target_os = "macos",
-    target_os = "netbsd",
-    target_os = "openbsd",
-    target_os = "cygwin",
-    target_os = "hurd",
-))]
 mod architecture;
 mod bitness;
+mod container;
+mod deployment;
+mod detector;
+mod family;
 mod info;
+mod kernel_version;
+mod lifecycle;
 #[cfg(not(windows))]
 mod matcher;
 mod os_type;
+mod package_manager;
+mod release_name;
+mod report;
+mod requirement;
 #[cfg(any(
     target_os = "aix",
     target_os = "dragonfly",
@@ -118,8 +120,33 @@ mod os_type;
 ))]
 mod uname;
 mod version;
-
-pub use crate::{bitness::Bitness, info::Info, os_type::Type, version::Version};
+mod virtualization;
+mod wsl;
+
+pub use crate::{
+    architecture::{Architecture, ParseArchitectureError},
+    bitness::Bitness,
+    container::Container,
+    deployment::Deployment,
+    detector::{Detector, Source},
+    family::Family,
+    info::Info,
+    kernel_version::KernelVersion,
+    lifecycle::{Date, Lifecycle, ParseDateError, SupportStatus},
+    os_type::{ParseTypeError, Type},
+    package_manager::PackageManager,
+    report::{Attempt, DetectionReport, Outcome, Property},
+    requirement::{ParseRequirementError, Requirement},
+    version::Version,
+    virtualization::Virtualization,
+    wsl::WslVersion,
+};
+
+/// Linux specific information.
+#[cfg(target_os = "linux")]
+pub mod linux {
+    pub use crate::imp::OsRelease;
+}
 
 /// Returns information about the current operating system (type, version, edition, etc.).
 ///
@@ -144,3 +171,56 @@ pub use crate::{bitness::Bitness, info::Info, os_type::Type, version::Version};
 pub fn get() -> Info {
     imp::current_platform()
 }
+
+/// Returns information about the Linux distribution installed under the given root directory.
+///
+/// This is useful for inspecting mounted images, unpacked container layers or chroots. Only the
+/// files under `root` are examined and no commands (such as `lsb_release`) are executed, so the
+/// bitness and architecture of the running system aren't reported. It is a shortcut for
+/// `Detector::new().root(root).detect()`.
+///
+/// # Examples
+///
+/// ```
+/// let info = os_info::from_root("/");
+/// println!("Distribution: {}", info.os_type());
+/// ```
+#[cfg(target_os = "linux")]
+pub fn from_root<P: AsRef<std::path::Path>>(root: P) -> Info {
+    Detector::new().root(root.as_ref()).detect()
+}
+
+/// Returns the container runtime or application sandbox the current process is running in.
+///
+/// `None` is returned when the process runs directly on the host system. Containers are only
+/// detected on Linux, other platforms always return `None`. It is a shortcut for
+/// `Detector::new().container()`.
+///
+/// # Examples
+///
+/// ```
+/// match os_info::container() {
+///     Some(container) => println!("Running in {container}"),
+///     None => println!("Not running in a container"),
+/// }
+/// ```
+pub fn container() -> Option<Container> {
+    Detector::new().container()
+}
+
+/// Returns the hypervisor the operating system is running on.
+///
+/// `None` is returned when it isn't possible to tell whether the system is virtualized, for
+/// example because the DMI data isn't readable. Virtualization is only detected on Linux, other
+/// platforms always return `None`. It is a shortcut for `Detector::new().virtualization()`.
+///
+/// # Examples
+///
+/// ```
+/// if let Some(virtualization) = os_info::virtualization() {
+///     println!("Running on {virtualization}");
+/// }
+/// ```
+pub fn virtualization() -> Option<Virtualization> {
+    Detector::new().virtualization()
+}
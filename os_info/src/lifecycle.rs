@@ -0,0 +1,747 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{Type, Version};
+
+/// Calendar date used to check the support status of the operating system.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::Date;
+///
+/// let date: Date = "2024-06-30".parse().unwrap();
+/// assert_eq!(Date::new(2024, 6, 30), Some(date));
+/// assert_eq!("2024-06-30", date.to_string());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Constructs a new date, returns `None` if the month or the day is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Date;
+    ///
+    /// assert!(Date::new(2024, 2, 29).is_some());
+    /// assert!(Date::new(2023, 2, 29).is_none());
+    /// ```
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self::ymd(year, month, day))
+    }
+
+    /// Returns the current date in UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Date;
+    ///
+    /// assert!(Date::today() > Date::new(2024, 1, 1).unwrap());
+    /// ```
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() / 86_400)
+            .unwrap_or(0);
+        Self::from_days(days)
+    }
+
+    /// Returns the year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Date;
+    ///
+    /// assert_eq!(2024, Date::new(2024, 6, 30).unwrap().year());
+    /// ```
+    pub fn year(self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, starting from `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Date;
+    ///
+    /// assert_eq!(6, Date::new(2024, 6, 30).unwrap().month());
+    /// ```
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting from `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Date;
+    ///
+    /// assert_eq!(30, Date::new(2024, 6, 30).unwrap().day());
+    /// ```
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    const fn ymd(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Converts the number of days since 1970-01-01 to a date, see
+    /// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    fn from_days(days: u64) -> Self {
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let day_of_era = z % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + u64::from(month <= 2);
+        Self::ymd(year as u16, month as u8, day as u8)
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    /// Parses the date in the `YYYY-MM-DD` format used by the os-release `SUPPORT_END` field.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseDateError {
+            value: s.to_owned(),
+        };
+
+        let mut parts = s.trim().splitn(3, '-');
+        let year = parts.next().and_then(|p| p.parse().ok());
+        let month = parts.next().and_then(|p| p.parse().ok());
+        let day = parts.next().and_then(|p| p.parse().ok());
+        match (year, month, day) {
+            (Some(year), Some(month), Some(day)) => Date::new(year, month, day).ok_or_else(err),
+            _ => Err(err()),
+        }
+    }
+}
+
+/// An error returned when parsing an invalid date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDateError {
+    value: String,
+}
+
+impl Display for ParseDateError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "invalid date: '{}'", self.value)
+    }
+}
+
+impl Error for ParseDateError {}
+
+/// Support status of the operating system release.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum SupportStatus {
+    /// The release receives regular updates.
+    Supported,
+    /// The regular support has ended, but security updates are available through an extended
+    /// (often paid) program, such as Ubuntu Pro, Debian LTS or Windows ESU.
+    Extended,
+    /// The release doesn't receive any updates.
+    EndOfLife,
+}
+
+impl Display for SupportStatus {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            SupportStatus::Supported => write!(f, "supported"),
+            SupportStatus::Extended => write!(f, "extended support"),
+            SupportStatus::EndOfLife => write!(f, "end of life"),
+        }
+    }
+}
+
+/// Lifecycle dates of the operating system release from the table embedded in the crate.
+///
+/// The table is a snapshot of the vendor announcements as of `Lifecycle::DATA_VERSION`, so the
+/// releases published after it are unknown and the dates that weren't announced are `None`.
+/// Windows releases are only included for the Home and Pro editions, because the Server,
+/// Enterprise, Education, LTSC and IoT editions are supported for longer.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::{Date, SupportStatus};
+///
+/// let info = os_info::get();
+/// if let Some(lifecycle) = info.lifecycle() {
+///     if lifecycle.status(Date::today()) == SupportStatus::EndOfLife {
+///         println!("{} {} is unsupported", info.os_type(), lifecycle.release());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Lifecycle {
+    release: &'static str,
+    general_availability: Date,
+    end_of_life: Option<Date>,
+    extended_support_end: Option<Date>,
+    lts: bool,
+}
+
+impl Lifecycle {
+    /// The date of the lifecycle table snapshot.
+    pub const DATA_VERSION: &'static str = "2025-10-15";
+
+    /// Returns the release version the lifecycle applies to, such as `22.04` or `10.0.22631`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = os_info::get();
+    /// if let Some(lifecycle) = info.lifecycle() {
+    ///     println!("{} {}", info.os_type(), lifecycle.release());
+    /// }
+    /// ```
+    pub fn release(&self) -> &'static str {
+        self.release
+    }
+
+    /// Returns the date the release has become generally available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = os_info::get();
+    /// if let Some(lifecycle) = info.lifecycle() {
+    ///     println!("Released on {}", lifecycle.general_availability());
+    /// }
+    /// ```
+    pub fn general_availability(&self) -> Date {
+        self.general_availability
+    }
+
+    /// Returns the end date of the regular support, `None` if it isn't announced yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = os_info::get();
+    /// if let Some(date) = info.lifecycle().and_then(|l| l.end_of_life()) {
+    ///     println!("Supported until {date}");
+    /// }
+    /// ```
+    pub fn end_of_life(&self) -> Option<Date> {
+        self.end_of_life
+    }
+
+    /// Returns the end date of the extended support, `None` if there is no extended support.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = os_info::get();
+    /// if let Some(date) = info.lifecycle().and_then(|l| l.extended_support_end()) {
+    ///     println!("Security updates are available until {date}");
+    /// }
+    /// ```
+    pub fn extended_support_end(&self) -> Option<Date> {
+        self.extended_support_end
+    }
+
+    /// Returns `true` for the long-term support releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = os_info::get();
+    /// if info.lifecycle().map_or(false, |l| l.is_lts()) {
+    ///     println!("LTS release");
+    /// }
+    /// ```
+    pub fn is_lts(&self) -> bool {
+        self.lts
+    }
+
+    /// Returns the support status of the release on the given date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Date;
+    ///
+    /// let info = os_info::get();
+    /// if let Some(lifecycle) = info.lifecycle() {
+    ///     println!("{}", lifecycle.status(Date::today()));
+    /// }
+    /// ```
+    pub fn status(&self, today: Date) -> SupportStatus {
+        status(today, self.end_of_life, self.extended_support_end)
+    }
+}
+
+pub(crate) fn status(
+    today: Date,
+    end_of_life: Option<Date>,
+    extended_support_end: Option<Date>,
+) -> SupportStatus {
+    if end_of_life.map_or(true, |eol| today < eol) {
+        SupportStatus::Supported
+    } else if extended_support_end.map_or(false, |end| today < end) {
+        SupportStatus::Extended
+    } else {
+        SupportStatus::EndOfLife
+    }
+}
+
+/// Returns the lifecycle of the given operating system release.
+pub(crate) fn find(
+    os_type: Type,
+    version: &Version,
+    edition: Option<&str>,
+) -> Option<&'static Lifecycle> {
+    if os_type == Type::Windows && !is_windows_consumer_edition(edition) {
+        return None;
+    }
+
+    lifecycles(os_type)
+        .iter()
+        .find(|lifecycle| version.matches_release(lifecycle.release))
+}
+
+/// Returns `true` for the Windows editions the table applies to. Server, Enterprise, Education,
+/// LTSC and IoT editions are serviced for longer and their dates aren't included.
+fn is_windows_consumer_edition(edition: Option<&str>) -> bool {
+    let edition = match edition {
+        Some(edition) => edition.to_ascii_lowercase(),
+        None => return false,
+    };
+    !["server", "enterprise", "education", "ltsc", "ltsb", "iot"]
+        .iter()
+        .any(|e| edition.contains(e))
+}
+
+const fn release(
+    release: &'static str,
+    general_availability: Date,
+    end_of_life: Option<Date>,
+    extended_support_end: Option<Date>,
+    lts: bool,
+) -> Lifecycle {
+    Lifecycle {
+        release,
+        general_availability,
+        end_of_life,
+        extended_support_end,
+        lts,
+    }
+}
+
+const fn d(year: u16, month: u8, day: u8) -> Option<Date> {
+    Some(Date::ymd(year, month, day))
+}
+
+const fn ga(year: u16, month: u8, day: u8) -> Date {
+    Date::ymd(year, month, day)
+}
+
+// Lifecycle dates collected from the vendor announcements, see also <https://endoflife.date>.
+
+/// Returns the lifecycle table of the given operating system type.
+fn lifecycles(os_type: Type) -> &'static [Lifecycle] {
+    match os_type {
+        Type::Ubuntu => &UBUNTU,
+        Type::Debian => &DEBIAN,
+        Type::RedHatEnterprise => &RHEL,
+        Type::CentOS => &CENTOS,
+        Type::RockyLinux => &ROCKY_LINUX,
+        Type::AlmaLinux => &ALMA_LINUX,
+        Type::OracleLinux => &ORACLE_LINUX,
+        Type::Fedora => &FEDORA,
+        Type::Amazon => &AMAZON,
+        Type::SUSE => &SUSE,
+        Type::openSUSE => &OPENSUSE,
+        Type::Alpine => &ALPINE,
+        Type::Windows => &WINDOWS,
+        Type::Macos => &MACOS,
+        _ => &[],
+    }
+}
+
+// The extended support is Expanded Security Maintenance (Ubuntu Pro).
+const UBUNTU: [Lifecycle; 9] = [
+    release(
+        "16.04",
+        ga(2016, 4, 21),
+        d(2021, 4, 30),
+        d(2026, 4, 30),
+        true,
+    ),
+    release(
+        "18.04",
+        ga(2018, 4, 26),
+        d(2023, 5, 31),
+        d(2028, 4, 1),
+        true,
+    ),
+    release(
+        "20.04",
+        ga(2020, 4, 23),
+        d(2025, 5, 29),
+        d(2030, 4, 2),
+        true,
+    ),
+    release("22.04", ga(2022, 4, 21), d(2027, 6, 1), d(2032, 4, 9), true),
+    release("23.10", ga(2023, 10, 12), d(2024, 7, 11), None, false),
+    release(
+        "24.04",
+        ga(2024, 4, 25),
+        d(2029, 5, 31),
+        d(2034, 4, 25),
+        true,
+    ),
+    release("24.10", ga(2024, 10, 10), d(2025, 7, 10), None, false),
+    release("25.04", ga(2025, 4, 17), d(2026, 1, 15), None, false),
+    release("25.10", ga(2025, 10, 9), d(2026, 7, 9), None, false),
+];
+
+// The extended support is Debian LTS.
+const DEBIAN: [Lifecycle; 5] = [
+    release("9", ga(2017, 6, 17), d(2020, 7, 6), d(2022, 6, 30), false),
+    release("10", ga(2019, 7, 6), d(2022, 9, 10), d(2024, 6, 30), false),
+    release("11", ga(2021, 8, 14), d(2024, 8, 14), d(2026, 8, 31), false),
+    release("12", ga(2023, 6, 10), d(2026, 6, 10), d(2028, 6, 30), false),
+    release("13", ga(2025, 8, 9), d(2028, 8, 9), d(2030, 6, 30), false),
+];
+
+// The regular support ends with the maintenance phase, the extended one is Extended Life Cycle
+// Support.
+const RHEL: [Lifecycle; 4] = [
+    release("7", ga(2014, 6, 10), d(2024, 6, 30), d(2028, 6, 30), true),
+    release("8", ga(2019, 5, 7), d(2029, 5, 31), d(2032, 5, 31), true),
+    release("9", ga(2022, 5, 18), d(2032, 5, 31), d(2035, 5, 31), true),
+    release("10", ga(2025, 5, 20), d(2035, 5, 31), d(2038, 5, 31), true),
+];
+
+const CENTOS: [Lifecycle; 4] = [
+    release("7", ga(2014, 7, 7), d(2024, 6, 30), None, true),
+    release("8", ga(2019, 9, 24), d(2021, 12, 31), None, true),
+    release("9", ga(2021, 12, 3), d(2027, 5, 31), None, false),
+    release("10", ga(2024, 12, 12), d(2030, 1, 1), None, false),
+];
+
+const ROCKY_LINUX: [Lifecycle; 3] = [
+    release("8", ga(2021, 6, 21), d(2029, 5, 31), None, true),
+    release("9", ga(2022, 7, 14), d(2032, 5, 31), None, true),
+    release("10", ga(2025, 6, 11), d(2035, 5, 31), None, true),
+];
+
+const ALMA_LINUX: [Lifecycle; 3] = [
+    release("8", ga(2021, 3, 30), d(2029, 3, 1), None, true),
+    release("9", ga(2022, 5, 26), d(2032, 5, 31), None, true),
+    release("10", ga(2025, 5, 27), d(2035, 5, 31), None, true),
+];
+
+const ORACLE_LINUX: [Lifecycle; 3] = [
+    release("7", ga(2014, 7, 23), d(2024, 12, 31), d(2028, 6, 30), true),
+    release("8", ga(2019, 7, 18), d(2029, 7, 31), d(2032, 7, 31), true),
+    release("9", ga(2022, 6, 30), d(2032, 6, 30), d(2035, 6, 30), true),
+];
+
+// New releases provide `SUPPORT_END` in the os-release file.
+const FEDORA: [Lifecycle; 4] = [
+    release("39", ga(2023, 11, 7), d(2024, 11, 26), None, false),
+    release("40", ga(2024, 4, 23), d(2025, 5, 13), None, false),
+    release("41", ga(2024, 10, 29), d(2025, 12, 15), None, false),
+    release("42", ga(2025, 4, 15), d(2026, 5, 13), None, false),
+];
+
+const AMAZON: [Lifecycle; 2] = [
+    release("2", ga(2018, 6, 26), d(2026, 6, 30), None, true),
+    release(
+        "2023",
+        ga(2023, 3, 15),
+        d(2027, 6, 30),
+        d(2029, 6, 30),
+        true,
+    ),
+];
+
+// The extended support is Long Term Service Pack Support.
+const SUSE: [Lifecycle; 2] = [
+    release(
+        "12",
+        ga(2014, 10, 27),
+        d(2024, 10, 31),
+        d(2027, 10, 31),
+        true,
+    ),
+    release("15", ga(2018, 7, 16), d(2031, 7, 31), d(2034, 7, 31), true),
+];
+
+const OPENSUSE: [Lifecycle; 2] = [
+    release("15.5", ga(2023, 6, 7), d(2024, 12, 31), None, false),
+    release("15.6", ga(2024, 6, 12), d(2026, 4, 30), None, false),
+];
+
+const ALPINE: [Lifecycle; 5] = [
+    release("3.18", ga(2023, 5, 9), d(2025, 5, 9), None, false),
+    release("3.19", ga(2023, 12, 7), d(2025, 11, 1), None, false),
+    release("3.20", ga(2024, 5, 22), d(2026, 4, 1), None, false),
+    release("3.21", ga(2024, 12, 5), d(2026, 11, 1), None, false),
+    release("3.22", ga(2025, 5, 30), d(2027, 5, 1), None, false),
+];
+
+// Dates of the Home and Pro editions, the extended support is Extended Security Updates. Other
+// editions have different dates, see `is_windows_consumer_edition`.
+const WINDOWS: [Lifecycle; 7] = [
+    release("10.0.19044", ga(2021, 11, 16), d(2023, 6, 13), None, false),
+    release(
+        "10.0.19045",
+        ga(2022, 10, 18),
+        d(2025, 10, 14),
+        d(2026, 10, 13),
+        false,
+    ),
+    release("10.0.22000", ga(2021, 10, 4), d(2023, 10, 10), None, false),
+    release("10.0.22621", ga(2022, 9, 20), d(2024, 10, 8), None, false),
+    release("10.0.22631", ga(2023, 10, 31), d(2025, 11, 11), None, false),
+    release("10.0.26100", ga(2024, 10, 1), d(2026, 10, 13), None, false),
+    release("10.0.26200", ga(2025, 9, 30), d(2027, 10, 12), None, false),
+];
+
+// Apple doesn't announce the end of support, but only the three latest releases receive security
+// updates.
+const MACOS: [Lifecycle; 7] = [
+    release("10.15", ga(2019, 10, 7), d(2022, 9, 12), None, false),
+    release("11", ga(2020, 11, 12), d(2023, 9, 26), None, false),
+    release("12", ga(2021, 10, 25), d(2024, 9, 16), None, false),
+    release("13", ga(2022, 10, 24), d(2025, 9, 15), None, false),
+    release("14", ga(2023, 9, 26), None, None, false),
+    release("15", ga(2024, 9, 16), None, None, false),
+    release("26", ga(2025, 9, 15), None, None, false),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_date() {
+        let data = [
+            ("2024-06-30", Some(Date::ymd(2024, 6, 30))),
+            (" 2024-02-29 ", Some(Date::ymd(2024, 2, 29))),
+            ("2023-02-29", None),
+            ("2024-13-01", None),
+            ("2024-06", None),
+            ("2024-06-30-01", None),
+            ("20240630", None),
+            ("", None),
+        ];
+
+        for (s, expected) in &data {
+            assert_eq!(*expected, s.parse().ok(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn parse_date_error_display() {
+        let err = "foo".parse::<Date>().unwrap_err();
+        assert_eq!("invalid date: 'foo'", err.to_string());
+    }
+
+    #[test]
+    fn date_from_days() {
+        let data = [
+            (0, "1970-01-01"),
+            (11_016, "2000-02-29"),
+            (19_722, "2023-12-31"),
+            (20_744, "2026-10-18"),
+        ];
+
+        for (days, expected) in &data {
+            assert_eq!(*expected, Date::from_days(*days).to_string(), "{days}");
+        }
+    }
+
+    #[test]
+    fn find_release() {
+        let data = [
+            (Type::Ubuntu, "22.04", Some("22.04")),
+            (Type::Ubuntu, "22.04.4", Some("22.04")),
+            (Type::Ubuntu, "22.10", None),
+            (Type::Debian, "12.7", Some("12")),
+            (Type::Amazon, "2023", Some("2023")),
+            (Type::Amazon, "2", Some("2")),
+            (Type::Windows, "10.0.22631", Some("10.0.22631")),
+            (Type::Macos, "10.15.7", Some("10.15")),
+            (Type::Macos, "14.5.0", Some("14")),
+            (Type::Fedora, "22.04", None),
+            (Type::Arch, "", None),
+        ];
+
+        for (os_type, version, expected) in &data {
+            let version = Version::from_string(*version);
+            assert_eq!(
+                *expected,
+                find(*os_type, &version, Some("Pro")).map(Lifecycle::release),
+                "{os_type:?} {version}"
+            );
+        }
+    }
+
+    #[test]
+    fn find_windows_edition() {
+        let data = [
+            (Some("Windows 11 Pro"), Some("10.0.26100")),
+            (Some("Windows 11 Home"), Some("10.0.26100")),
+            (Some("Windows Server 2025 Datacenter"), None),
+            (Some("Windows 11 Enterprise"), None),
+            (Some("Windows 11 Education"), None),
+            (Some("Windows 10 Enterprise LTSC 2021"), None),
+            (Some("Windows 10 IoT Enterprise LTSC"), None),
+            (None, None),
+        ];
+
+        let version = Version::from_string("10.0.26100");
+        for (edition, expected) in &data {
+            assert_eq!(
+                *expected,
+                find(Type::Windows, &version, *edition).map(Lifecycle::release),
+                "{edition:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn release_status() {
+        let data = [
+            (
+                Type::Ubuntu,
+                "24.04",
+                "2026-10-18",
+                SupportStatus::Supported,
+            ),
+            (Type::Ubuntu, "20.04", "2026-10-18", SupportStatus::Extended),
+            (
+                Type::Ubuntu,
+                "20.04",
+                "2025-05-28",
+                SupportStatus::Supported,
+            ),
+            (Type::Ubuntu, "20.04", "2025-05-29", SupportStatus::Extended),
+            (
+                Type::Ubuntu,
+                "24.10",
+                "2026-10-18",
+                SupportStatus::EndOfLife,
+            ),
+            (Type::Debian, "10", "2026-10-18", SupportStatus::EndOfLife),
+            (
+                Type::Windows,
+                "10.0.19045",
+                "2026-01-01",
+                SupportStatus::Extended,
+            ),
+            (Type::Macos, "15.1", "2030-01-01", SupportStatus::Supported),
+        ];
+
+        for (os_type, version, today, expected) in &data {
+            let lifecycle = find(*os_type, &Version::from_string(*version), Some("Pro")).unwrap();
+            assert_eq!(
+                *expected,
+                lifecycle.status(date(today)),
+                "{version} {today}"
+            );
+        }
+    }
+
+    #[test]
+    fn table_is_consistent() {
+        let os_types = [
+            Type::Ubuntu,
+            Type::Debian,
+            Type::RedHatEnterprise,
+            Type::CentOS,
+            Type::RockyLinux,
+            Type::AlmaLinux,
+            Type::OracleLinux,
+            Type::Fedora,
+            Type::Amazon,
+            Type::SUSE,
+            Type::openSUSE,
+            Type::Alpine,
+            Type::Windows,
+            Type::Macos,
+        ];
+
+        for (os_type, lifecycle) in os_types
+            .iter()
+            .flat_map(|t| lifecycles(*t).iter().map(move |l| (t, l)))
+        {
+            let release = lifecycle.release;
+            let ga = lifecycle.general_availability;
+            assert_eq!(
+                Some(ga),
+                Date::new(ga.year, ga.month, ga.day),
+                "{os_type:?} {release}"
+            );
+            if let Some(eol) = lifecycle.end_of_life {
+                assert!(ga < eol, "{:?} {}", os_type, release);
+                if let Some(end) = lifecycle.extended_support_end {
+                    assert!(eol < end, "{:?} {}", os_type, release);
+                }
+            }
+            assert_eq!(
+                Some(lifecycle),
+                find(*os_type, &Version::from_string(release), Some("Pro")),
+                "{os_type:?} {release}"
+            );
+        }
+    }
+
+    #[test]
+    fn status_display() {
+        assert_eq!("supported", SupportStatus::Supported.to_string());
+        assert_eq!("extended support", SupportStatus::Extended.to_string());
+        assert_eq!("end of life", SupportStatus::EndOfLife.to_string());
+    }
+}
@@ -0,0 +1,187 @@
+// spell-checker:ignore containerenv, dockerenv, nspawn, kubepods, libpod, serviceaccount, environ
+
+use std::{fs, path::Path};
+
+use log::trace;
+
+use crate::Container;
+
+/// Detects the container runtime by looking at the files under `root`.
+///
+/// Checks are ordered from the most specific to the most generic one, so for example a Kubernetes
+/// pod running on Docker is reported as `Container::Kubernetes`.
+pub fn get(root: &Path) -> Option<Container> {
+    let container = sandbox(root)
+        .or_else(|| marker_files(root))
+        .or_else(|| systemd_container(root))
+        .or_else(|| init_environment(root))
+        .or_else(|| init_cgroup(root));
+
+    trace!("Detected {:?} container under {:?}", container, root);
+    container
+}
+
+/// Application sandboxes that run on top of the host system.
+fn sandbox(root: &Path) -> Option<Container> {
+    if root.join(".flatpak-info").exists() {
+        return Some(Container::Flatpak);
+    }
+
+    let environment = read(root, "proc/self/environ")?;
+    if environment.split('\0').any(|v| v.starts_with("SNAP_NAME=")) {
+        return Some(Container::Snap);
+    }
+
+    None
+}
+
+/// Files created by the container runtimes themselves.
+fn marker_files(root: &Path) -> Option<Container> {
+    if root
+        .join("var/run/secrets/kubernetes.io/serviceaccount")
+        .exists()
+    {
+        Some(Container::Kubernetes)
+    } else if root.join("run/.containerenv").exists() {
+        Some(Container::Podman)
+    } else if root.join(".dockerenv").exists() {
+        Some(Container::Docker)
+    } else {
+        None
+    }
+}
+
+/// The container type that systemd records at boot.
+fn systemd_container(root: &Path) -> Option<Container> {
+    read(root, "run/systemd/container").map(|value| from_name(value.trim()))
+}
+
+/// The `container` environment variable of the init process.
+fn init_environment(root: &Path) -> Option<Container> {
+    read(root, "proc/1/environ")?
+        .split('\0')
+        .find_map(|v| v.strip_prefix("container="))
+        .map(from_name)
+}
+
+/// Control group paths of the init process that are specific to container runtimes.
+fn init_cgroup(root: &Path) -> Option<Container> {
+    let cgroup = read(root, "proc/1/cgroup")?;
+    if cgroup.contains("/kubepods") {
+        Some(Container::Kubernetes)
+    } else if cgroup.contains("libpod") {
+        Some(Container::Podman)
+    } else if cgroup.contains("docker") {
+        Some(Container::Docker)
+    } else if cgroup.contains("/lxc") {
+        Some(Container::Lxc)
+    } else {
+        None
+    }
+}
+
+fn from_name(name: &str) -> Container {
+    match name {
+        "docker" => Container::Docker,
+        "podman" => Container::Podman,
+        "lxc" | "lxc-libvirt" => Container::Lxc,
+        "systemd-nspawn" => Container::SystemdNspawn,
+        "flatpak" => Container::Flatpak,
+        _ => Container::Unknown,
+    }
+}
+
+fn read(root: &Path, path: &str) -> Option<String> {
+    fs::read(root.join(path))
+        .ok()
+        .map(|content| String::from_utf8_lossy(&content).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn docker() {
+        let root = Path::new("src/linux/tests/Container_Docker");
+        assert_eq!(Some(Container::Docker), get(root));
+    }
+
+    #[test]
+    fn docker_cgroup() {
+        let root = Path::new("src/linux/tests/Container_DockerCgroup");
+        assert_eq!(Some(Container::Docker), get(root));
+    }
+
+    #[test]
+    fn podman() {
+        let root = Path::new("src/linux/tests/Container_Podman");
+        assert_eq!(Some(Container::Podman), get(root));
+    }
+
+    #[test]
+    fn lxc() {
+        let root = Path::new("src/linux/tests/Container_Lxc");
+        assert_eq!(Some(Container::Lxc), get(root));
+    }
+
+    #[test]
+    fn systemd_nspawn() {
+        let root = Path::new("src/linux/tests/Container_Nspawn");
+        assert_eq!(Some(Container::SystemdNspawn), get(root));
+    }
+
+    #[test]
+    fn kubernetes() {
+        let root = Path::new("src/linux/tests/Container_Kubernetes");
+        assert_eq!(Some(Container::Kubernetes), get(root));
+    }
+
+    #[test]
+    fn flatpak() {
+        let root = Path::new("src/linux/tests/Container_Flatpak");
+        assert_eq!(Some(Container::Flatpak), get(root));
+    }
+
+    #[test]
+    fn snap() {
+        let root = Path::new("src/linux/tests/Container_Snap");
+        assert_eq!(Some(Container::Snap), get(root));
+    }
+
+    #[test]
+    fn unknown() {
+        let root = Path::new("src/linux/tests/Container_Unknown");
+        assert_eq!(Some(Container::Unknown), get(root));
+    }
+
+    #[test]
+    fn host() {
+        let root = Path::new("src/linux/tests/Ubuntu");
+        assert_eq!(None, get(root));
+    }
+
+    #[test]
+    fn no_path() {
+        let root = Path::new("src/linux/tests/none_no_path");
+        assert_eq!(None, get(root));
+    }
+
+    #[test]
+    fn names() {
+        let data = [
+            ("docker", Container::Docker),
+            ("podman", Container::Podman),
+            ("lxc", Container::Lxc),
+            ("lxc-libvirt", Container::Lxc),
+            ("systemd-nspawn", Container::SystemdNspawn),
+            ("flatpak", Container::Flatpak),
+            ("oci", Container::Unknown),
+        ];
+
+        for (name, expected) in &data {
+            assert_eq!(*expected, from_name(name));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// spell-checker:ignore kinoite, microos, mountinfo, onyx, sericea, silverblue
+
+use std::{fs, path::Path};
+
+use log::trace;
+
+use super::OsRelease;
+use crate::{Deployment, Type};
+
+/// os-release `VARIANT_ID` values of the OSTree based Fedora editions. The newer editions use the
+/// `-atomic` suffix instead, such as `sway-atomic`.
+const OSTREE_VARIANTS: [&str; 6] = ["silverblue", "kinoite", "sericea", "onyx", "coreos", "iot"];
+
+/// os-release `ID` values of the OSTree based Universal Blue images.
+const OSTREE_IDS: [&str; 2] = ["bazzite", "bluefin"];
+
+/// os-release `ID` values of the systems updated by `transactional-update`.
+const TRANSACTIONAL_IDS: [&str; 3] = ["opensuse-microos", "sle-micro", "sl-micro"];
+
+/// Detects the immutable deployment model by looking at the files under `root` and the parsed
+/// os-release file. Returns `None` for the traditional package based systems.
+pub fn get(os_type: Type, os_release: Option<&OsRelease>, root: &Path) -> Option<Deployment> {
+    let deployment = ostree(os_release, root)
+        .or_else(|| transactional(os_release, root))
+        .or_else(|| match (os_type, os_release.and_then(OsRelease::id)) {
+            (Type::NixOS, _) | (_, Some("nixos")) => Some(Deployment::Nix),
+            (_, Some("ubuntu-core")) => Some(Deployment::UbuntuCore),
+            _ => None,
+        })
+        .or_else(|| read_only_usr(root));
+
+    trace!("Detected {:?} deployment under {:?}", deployment, root);
+    deployment
+}
+
+/// Only the marker file created when booting an OSTree deployment and the os-release identifiers
+/// are checked, because the `rpm-ostree` tool can be installed on any Fedora system.
+fn ostree(os_release: Option<&OsRelease>, root: &Path) -> Option<Deployment> {
+    let is_ostree_id = os_release
+        .and_then(OsRelease::id)
+        .map_or(false, |id| OSTREE_IDS.contains(&id));
+    let is_ostree_variant = os_release
+        .and_then(OsRelease::variant_id)
+        .map_or(false, |v| {
+            OSTREE_VARIANTS.contains(&v) || v.ends_with("-atomic")
+        });
+
+    if is_ostree_id || is_ostree_variant || root.join("run/ostree-booted").exists() {
+        Some(Deployment::Ostree)
+    } else {
+        None
+    }
+}
+
+fn transactional(os_release: Option<&OsRelease>, root: &Path) -> Option<Deployment> {
+    let is_transactional_id = os_release
+        .and_then(OsRelease::id)
+        .map_or(false, |id| TRANSACTIONAL_IDS.contains(&id));
+
+    if is_transactional_id || root.join("usr/sbin/transactional-update").exists() {
+        Some(Deployment::Transactional)
+    } else {
+        None
+    }
+}
+
+/// Checks the mount options of `/usr` if it is a separate mount point. A read-only `/` isn't
+/// enough, because containers are often run that way (`docker run --read-only`).
+fn read_only_usr(root: &Path) -> Option<Deployment> {
+    let mounts = fs::read_to_string(root.join("proc/mounts")).ok()?;
+    // The last entry shadows the previous mounts at the same point.
+    let options = mounts.lines().rev().find_map(|line| {
+        let mut fields = line.split_whitespace().skip(1);
+        match (fields.next(), fields.nth(1)) {
+            (Some("/usr"), Some(options)) => Some(options),
+            _ => None,
+        }
+    })?;
+    if options.split(',').any(|o| o == "ro") {
+        Some(Deployment::ReadOnly)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn ostree_booted() {
+        let root = Path::new("src/linux/tests/Deployment_OstreeBooted");
+        assert_eq!(Some(Deployment::Ostree), get(Type::Linux, None, root));
+    }
+
+    #[test]
+    fn rpm_ostree_installed() {
+        let root = Path::new("src/linux/tests/PackageManager_Bluefin");
+        assert_eq!(None, get(Type::Linux, None, root));
+    }
+
+    #[test]
+    fn transactional_update() {
+        let root = Path::new("src/linux/tests/Deployment_MicroOS");
+        assert_eq!(
+            Some(Deployment::Transactional),
+            get(Type::Linux, None, root)
+        );
+    }
+
+    #[test]
+    fn read_only_usr() {
+        let root = Path::new("src/linux/tests/Deployment_ReadOnlyUsr");
+        assert_eq!(Some(Deployment::ReadOnly), get(Type::Linux, None, root));
+    }
+
+    #[test]
+    fn read_only_root() {
+        let root = Path::new("src/linux/tests/Deployment_ReadOnlyRoot");
+        assert_eq!(None, get(Type::Linux, None, root));
+    }
+
+    #[test]
+    fn remounted_usr() {
+        let root = Path::new("src/linux/tests/Deployment_Mutable");
+        assert_eq!(None, get(Type::Linux, None, root));
+    }
+
+    #[test]
+    fn mutable() {
+        let root = Path::new("src/linux/tests/PackageManager_Fedora");
+        assert_eq!(None, get(Type::Linux, None, root));
+    }
+
+    #[test]
+    fn no_path() {
+        let root = Path::new("src/linux/tests/none_no_path");
+        assert_eq!(None, get(Type::Linux, None, root));
+    }
+
+    #[test]
+    fn os_release() {
+        let data = [
+            (
+                Type::Fedora,
+                "ID=fedora\nVARIANT_ID=kinoite",
+                Some(Deployment::Ostree),
+            ),
+            (
+                Type::Fedora,
+                "ID=fedora\nVARIANT_ID=sway-atomic",
+                Some(Deployment::Ostree),
+            ),
+            (Type::Fedora, "ID=fedora\nVARIANT_ID=workstation", None),
+            (Type::Bluefin, "ID=bluefin", Some(Deployment::Ostree)),
+            (Type::Bazzite, "ID=bazzite", Some(Deployment::Ostree)),
+            (
+                Type::openSUSE,
+                "ID=opensuse-microos",
+                Some(Deployment::Transactional),
+            ),
+            (Type::NixOS, "ID=nixos", Some(Deployment::Nix)),
+            (Type::Linux, "ID=ubuntu-core", Some(Deployment::UbuntuCore)),
+            (Type::Ubuntu, "ID=ubuntu", None),
+        ];
+
+        let root = Path::new("src/linux/tests/none_no_path");
+        for (os_type, content, expected) in &data {
+            let release = OsRelease::parse(content);
+            assert_eq!(
+                *expected,
+                get(*os_type, Some(&release), root),
+                "{content:?}"
+            );
+        }
+    }
+}
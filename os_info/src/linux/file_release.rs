@@ -1,21 +1,47 @@
-// spell-checker:ignore sles, AOSCOS, eneon
+// spell-checker:ignore sles, AOSCOS, eneon, rawhide, devel
 
 use std::{fmt, fs::File, io::Read, path::Path};
 
 use log::{trace, warn};
 
-use crate::{matcher::Matcher, Bitness, Info, Type, Version};
+use crate::{
+    matcher::Matcher,
+    report::{describe, Attempt, Outcome, Property},
+    Bitness, Info, Source, Type, Version,
+};
 
-pub fn get() -> Option<Info> {
-    retrieve(&DISTRIBUTIONS, "/")
+/// Retrieves information from the os-release file.
+pub fn get_from_os_release(root: &Path, attempts: &mut Vec<Attempt>) -> Option<Info> {
+    retrieve(&DISTRIBUTIONS[..1], root, Source::OsRelease, attempts)
 }
 
-fn retrieve(distributions: &[ReleaseInfo], root: &str) -> Option<Info> {
+/// Retrieves information from the distribution specific release files.
+pub fn get_from_release_files(root: &Path, attempts: &mut Vec<Attempt>) -> Option<Info> {
+    retrieve(&DISTRIBUTIONS[1..], root, Source::ReleaseFiles, attempts)
+}
+
+fn retrieve(
+    distributions: &[ReleaseInfo],
+    root: impl AsRef<Path>,
+    source: Source,
+    attempts: &mut Vec<Attempt>,
+) -> Option<Info> {
+    let root = root.as_ref();
+
     for release_info in distributions {
-        let path = Path::new(root).join(release_info.path);
+        let path = root.join(release_info.path);
+        let attempt = |outcome| {
+            Attempt::new(
+                Property::Distribution,
+                Some(source),
+                path.display().to_string(),
+                outcome,
+            )
+        };
 
         if !path.exists() {
             trace!("Path '{}' doesn't exist", release_info.path);
+            attempts.push(attempt(Outcome::NotFound));
             continue;
         }
 
@@ -23,6 +49,7 @@ fn retrieve(distributions: &[ReleaseInfo], root: &str) -> Option<Info> {
             Ok(val) => val,
             Err(e) => {
                 warn!("Unable to open {:?} file: {:?}", &path, e);
+                attempts.push(attempt(Outcome::Failed(e.to_string())));
                 continue;
             }
         };
@@ -30,41 +57,80 @@ fn retrieve(distributions: &[ReleaseInfo], root: &str) -> Option<Info> {
         let mut file_content = String::new();
         if let Err(e) = file.read_to_string(&mut file_content) {
             warn!("Unable to read {:?} file: {:?}", &path, e);
+            attempts.push(attempt(Outcome::Failed(e.to_string())));
             continue;
         }
 
-        let os_type = (release_info.os_type)(&file_content);
+        let os_type = (release_info.os_type)(&file_content, root);
 
         // If os_type is indeterminate, try the next release_info
         if os_type.is_none() {
+            attempts.push(attempt(Outcome::Unrecognized(summary(&file_content))));
             continue;
         }
 
         let version = (release_info.version)(&file_content);
         let codename = (release_info.codename)(&file_content);
 
-        return Some(Info {
+        let info = Info {
             // Unwrap is OK here because of the `os_type.is_none()` check above.
             os_type: os_type.unwrap(),
             version: version.unwrap_or(Version::Unknown),
             codename,
             bitness: Bitness::Unknown,
             ..Default::default()
-        });
+        };
+        attempts.push(attempt(Outcome::Used(describe(&info))));
+        return Some(info);
     }
 
     // Failed to determine os info
     None
 }
 
+/// Returns the `ID` line of an os-release file or the first line of other release files.
+fn summary(content: &str) -> String {
+    let mut lines = content.lines().map(str::trim).filter(|l| !l.is_empty());
+    let first = lines.clone().next();
+    lines
+        .find(|l| l.starts_with("ID="))
+        .or(first)
+        .unwrap_or_default()
+        .to_owned()
+}
+
+/// Returns the closest known distribution from the `ID_LIKE` list, skipping `os_type` itself.
+pub fn based_on(id_like: &[&str], os_type: Type) -> Option<Type> {
+    id_like
+        .iter()
+        .filter_map(|id| Type::from_os_release_id(id))
+        .find(|&t| t != os_type)
+}
+
+/// Returns the pre-release label if the os-release file describes a development release, such as
+/// Fedora Rawhide or an Ubuntu development branch.
+fn development_release(release: &str) -> Option<&'static str> {
+    let value = |key| Matcher::KeyValue { key }.find(release);
+
+    if value("REDHAT_SUPPORT_PRODUCT_VERSION").as_deref() == Some("rawhide") {
+        Some("rawhide")
+    } else if value("RELEASE_TYPE").as_deref() == Some("development")
+        || value("PRETTY_NAME").map_or(false, |n| n.ends_with("(development branch)"))
+    {
+        Some("devel")
+    } else {
+        None
+    }
+}
+
 /// Struct containing information on how to parse distribution info from a release file.
 #[derive(Clone)]
 struct ReleaseInfo<'a> {
     /// Relative path to the release file this struct corresponds to from root.
     path: &'a str,
 
-    /// A closure that determines the os type from the release file contents.
-    os_type: for<'b> fn(&'b str) -> Option<Type>,
+    /// A closure that determines the os type from the release file contents and root directory.
+    os_type: for<'b> fn(&'b str, &'b Path) -> Option<Type>,
 
     /// A closure that determines the os version from the release file contents.
     version: for<'b> fn(&'b str) -> Option<Version>,
@@ -77,7 +143,10 @@ impl fmt::Debug for ReleaseInfo<'_> {
     fn fmt<'a>(&'a self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ReleaseInfo")
             .field("path", &self.path)
-            .field("os_type", &(self.os_type as fn(&'a str) -> Option<Type>))
+            .field(
+                "os_type",
+                &(self.os_type as fn(&'a str, &'a Path) -> Option<Type>),
+            )
             .field("version", &(self.version as fn(&'a str) -> Option<Version>))
             .field(
                 "codename",
@@ -93,97 +162,41 @@ static DISTRIBUTIONS: [ReleaseInfo; 6] = [
     // Keep this first; most modern distributions have this file.
     ReleaseInfo {
         path: "etc/os-release",
-        os_type: |release| {
-            Matcher::KeyValue { key: "ID" }
-                .find(release)
-                .and_then(|id| match id.as_str() {
-                    // os-release information collected from
-                    // https://github.com/chef/os_release
-                    "almalinux" => Some(Type::AlmaLinux),
-                    "alpaquita" => Some(Type::Alpaquita),
-                    "alpine" => Some(Type::Alpine),
-                    "altlinux" => Some(Type::ALTLinux),
-                    "amzn" => Some(Type::Amazon),
-                    //"antergos" => Antergos
-                    "aosc" => Some(Type::AOSC),
-                    "arch" => Some(Type::Arch),
-                    "archarm" => Some(Type::Arch),
-                    "artix" => Some(Type::Artix),
-                    "azurelinux" => Some(Type::AzureLinux),
-                    "bazzite" => Some(Type::Bazzite),
-                    "bluefin" => Some(Type::Bluefin),
-                    "cachyos" => Some(Type::CachyOS),
-                    "centos" => Some(Type::CentOS),
-                    //"clear-linux-os" => ClearLinuxOS
-                    //"clearos" => ClearOS
-                    //"coreos"
-                    //"cumulus-linux" => Cumulus
-                    "debian" => {
-                        // Check if it's actually Raspberry Pi OS or Parrot OS
-                        if std::path::Path::new("/etc/rpi-issue").exists() {
-                            Some(Type::Raspbian)
-                        } else if (Matcher::KeyValue { key: "NAME" })
-                            .find(release)
-                            .as_deref()
-                            == Some("Parrot Security")
-                        {
-                            Some(Type::Parrot)
-                        } else {
-                            Some(Type::Debian)
-                        }
+        os_type: |release, root| {
+            let id = Matcher::KeyValue { key: "ID" }.find(release)?;
+            match Type::from_os_release_id(&id) {
+                Some(Type::Debian) => {
+                    // Check if it's actually Raspberry Pi OS or Parrot OS
+                    if root.join("etc/rpi-issue").exists() {
+                        Some(Type::Raspbian)
+                    } else if (Matcher::KeyValue { key: "NAME" }).find(release).as_deref()
+                        == Some("Parrot Security")
+                    {
+                        Some(Type::Parrot)
+                    } else {
+                        Some(Type::Debian)
                     }
-                    //"devuan" => Devuan
-                    "elementary" => Some(Type::Elementary),
-                    "fedora" => Some(Type::Fedora),
-                    //"gentoo" => Gentoo
-                    "instantos" => Some(Type::InstantOS),
-                    //"ios_xr" => ios_xr
-                    "kali" => Some(Type::Kali),
-                    "neon" => Some(Type::KDENeon),
-                    //"mageia" => Mageia
-                    //"manjaro" => Manjaro
-                    "manjaro-arm" => Some(Type::Manjaro),
-                    "linuxmint" => Some(Type::Mint),
-                    "mariner" => Some(Type::Mariner),
-                    //"nexus" => Nexus
-                    "nixos" => Some(Type::NixOS),
-                    "nobara" => Some(Type::Nobara),
-                    "Uos" => Some(Type::Uos),
-                    "opencloudos" => Some(Type::OpenCloudOS),
-                    "openEuler" => Some(Type::openEuler),
-                    "ol" => Some(Type::OracleLinux),
-                    "opensuse" => Some(Type::openSUSE),
-                    "opensuse-leap" => Some(Type::openSUSE),
-                    "opensuse-microos" => Some(Type::openSUSE),
-                    "opensuse-tumbleweed" => Some(Type::openSUSE),
-                    "parrot" => Some(Type::Parrot),
-                    "pika" => Some(Type::PikaOS),
-                    //"rancheros" => RancherOS
-                    //"raspbian" => Raspbian
-                    // note XBian also uses "raspbian"
-                    "rhel" => Some(Type::RedHatEnterprise),
-                    "rocky" => Some(Type::RockyLinux),
-                    //"sabayon" => Sabayon
-                    //"scientific" => Scientific
-                    //"slackware" => Slackware
-                    "sled" => Some(Type::SUSE), // SUSE desktop
-                    "sles" => Some(Type::SUSE),
-                    "sles_sap" => Some(Type::SUSE), // SUSE SAP
-                    "ubuntu" => Some(Type::Ubuntu),
-                    "ultramarine" => Some(Type::Ultramarine),
-                    //"virtuozzo" => Virtuozzo
-                    "void" => Some(Type::Void),
-                    "zorin" => Some(Type::Zorin),
-                    //"XCP-ng" => xcp-ng
-                    //"xenenterprise" => xcp-ng
-                    //"xenserver" => xcp-ng
-                    _ => None,
-                })
+                }
+                // XBian also uses the `raspbian` ID, so Raspberry Pi OS is only detected by
+                // `etc/rpi-issue` above.
+                Some(os_type) if os_type != Type::Raspbian => Some(os_type),
+                // An unknown distribution derived from a known one is still reported as Linux
+                // instead of falling through to the other release files.
+                _ => {
+                    let id_like = Matcher::KeyValue { key: "ID_LIKE" }.find(release)?;
+                    let id_like: Vec<_> = id_like.split_whitespace().collect();
+                    based_on(&id_like, Type::Linux).map(|_| Type::Linux)
+                }
+            }
         },
         version: |release| {
-            Matcher::KeyValue { key: "VERSION_ID" }
+            let version = Matcher::KeyValue { key: "VERSION_ID" }
                 .find(release)
-                .map(Version::from_string)
+                .map(Version::from_string)?;
+            Some(match development_release(release) {
+                Some(label) => version.with_pre_release(label),
+                None => version,
+            })
         },
         codename: |release| {
             Matcher::KeyValue {
@@ -197,7 +210,7 @@ static DISTRIBUTIONS: [ReleaseInfo; 6] = [
     // Older distributions must have their specific release file parsed.
     ReleaseInfo {
         path: "etc/mariner-release",
-        os_type: |_| Some(Type::Mariner),
+        os_type: |_, _| Some(Type::Mariner),
         version: |release| {
             Matcher::PrefixedVersion {
                 prefix: "CBL-Mariner",
@@ -209,7 +222,7 @@ static DISTRIBUTIONS: [ReleaseInfo; 6] = [
     },
     ReleaseInfo {
         path: "etc/centos-release",
-        os_type: |_| Some(Type::CentOS),
+        os_type: |_, _| Some(Type::CentOS),
         version: |release| {
             Matcher::PrefixedVersion { prefix: "release" }
                 .find(release)
@@ -219,7 +232,7 @@ static DISTRIBUTIONS: [ReleaseInfo; 6] = [
     },
     ReleaseInfo {
         path: "etc/fedora-release",
-        os_type: |_| Some(Type::Fedora),
+        os_type: |_, _| Some(Type::Fedora),
         version: |release| {
             Matcher::PrefixedVersion { prefix: "release" }
                 .find(release)
@@ -229,13 +242,13 @@ static DISTRIBUTIONS: [ReleaseInfo; 6] = [
     },
     ReleaseInfo {
         path: "etc/alpine-release",
-        os_type: |_| Some(Type::Alpine),
+        os_type: |_, _| Some(Type::Alpine),
         version: |release| Matcher::AllTrimmed.find(release).map(Version::from_string),
         codename: |_| None,
     },
     ReleaseInfo {
         path: "etc/redhat-release",
-        os_type: |_| Some(Type::RedHatEnterprise),
+        os_type: |_, _| Some(Type::RedHatEnterprise),
         version: |release| {
             Matcher::PrefixedVersion { prefix: "release" }
                 .find(release)
@@ -250,6 +263,49 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    fn retrieve(distributions: &[ReleaseInfo], root: &str) -> Option<Info> {
+        super::retrieve(distributions, root, Source::ReleaseFiles, &mut Vec::new())
+    }
+
+    #[test]
+    fn attempts() {
+        let mut attempts = Vec::new();
+        let info = get_from_release_files(Path::new("src/linux/tests/CentOS"), &mut attempts);
+        assert_eq!(Some(Type::CentOS), info.map(|i| i.os_type()));
+
+        let used = attempts.pop().unwrap();
+        assert_eq!(Property::Distribution, used.property());
+        assert_eq!(Some(Source::ReleaseFiles), used.source());
+        assert_eq!("src/linux/tests/CentOS/etc/centos-release", used.target());
+        assert!(used.is_used());
+        assert!(attempts
+            .iter()
+            .all(|a| a.outcome() == &Outcome::NotFound && a.target().starts_with("src/linux")));
+    }
+
+    #[test]
+    fn attempts_os_release() {
+        let mut attempts = Vec::new();
+        let info = get_from_os_release(Path::new("src/linux/tests/Devuan"), &mut attempts);
+        assert_eq!(Some(Type::Linux), info.map(|i| i.os_type()));
+        assert_eq!(
+            vec![Attempt::new(
+                Property::Distribution,
+                Some(Source::OsRelease),
+                "src/linux/tests/Devuan/etc/os-release",
+                Outcome::Used("Linux 5.0.0 (daedalus)".to_owned()),
+            )],
+            attempts
+        );
+    }
+
+    #[test]
+    fn summary_line() {
+        assert_eq!("ID=debian", summary("NAME=\"Debian\"\n ID=debian\n"));
+        assert_eq!("Devuan 5", summary("\nDevuan 5\nfoo\n"));
+        assert_eq!("", summary(""));
+    }
+
     #[test]
     fn almalinux_9_0_release() {
         let root = "src/linux/tests/AlmaLinux-9.0";
@@ -481,6 +537,17 @@ mod tests {
         assert_eq!(info.codename, Some("bullseye".to_string()));
     }
 
+    #[test]
+    fn devuan_os_release() {
+        let root = "src/linux/tests/Devuan";
+
+        let info = retrieve(&DISTRIBUTIONS, root).unwrap();
+        assert_eq!(info.os_type(), Type::Linux);
+        assert_eq!(info.version, Version::Semantic(5, 0, 0));
+        assert_eq!(info.edition, None);
+        assert_eq!(info.codename, Some("daedalus".to_string()));
+    }
+
     #[test]
     fn fedora_32_os_release() {
         let root = "src/linux/tests/Fedora_32";
@@ -503,6 +570,17 @@ mod tests {
         assert_eq!(info.codename, None);
     }
 
+    #[test]
+    fn fedora_rawhide_os_release() {
+        let root = "src/linux/tests/Fedora_Rawhide";
+
+        let info = retrieve(&DISTRIBUTIONS, root).unwrap();
+        assert_eq!(info.os_type(), Type::Fedora);
+        assert_eq!(info.version, Version::from_string("42.0.0-rawhide"));
+        assert!(info.version.is_prerelease());
+        assert_eq!(info.codename, None);
+    }
+
     #[test]
     fn fedora_release() {
         let root = "src/linux/tests/Fedora";
@@ -717,6 +795,17 @@ mod tests {
         assert_eq!(info.codename, None);
     }
 
+    #[test]
+    fn raspbian_os_release() {
+        let root = "src/linux/tests/Raspbian";
+
+        let info = retrieve(&DISTRIBUTIONS, root).unwrap();
+        assert_eq!(info.os_type(), Type::Raspbian);
+        assert_eq!(info.version, Version::Semantic(12, 0, 0));
+        assert_eq!(info.edition, None);
+        assert_eq!(info.codename, Some("bookworm".to_string()));
+    }
+
     #[test]
     fn redhat_release() {
         let root = "src/linux/tests/RedHatEnterprise";
@@ -783,6 +872,17 @@ mod tests {
         assert_eq!(info.codename, Some("cosmic".to_string()));
     }
 
+    #[test]
+    fn ubuntu_devel_os_release() {
+        let root = "src/linux/tests/Ubuntu_Devel";
+
+        let info = retrieve(&DISTRIBUTIONS, root).unwrap();
+        assert_eq!(info.os_type(), Type::Ubuntu);
+        assert_eq!(info.version, Version::from_string("25.4.0-devel"));
+        assert!(info.version.is_prerelease());
+        assert_eq!(info.codename, Some("plucky".to_string()));
+    }
+
     #[test]
     fn ultramarine_os_release() {
         let root = "src/linux/tests/Ultramarine";
@@ -824,7 +924,7 @@ mod tests {
         assert_eq!(info.os_type(), Type::Parrot);
         assert_eq!(info.version, Version::Semantic(7, 2, 0));
         assert_eq!(info.edition, None);
-        assert_eq!(info.codename, None);
+        assert_eq!(info.codename, Some("echo".to_string()));
     }
 
     #[test]
@@ -838,6 +938,26 @@ mod tests {
         assert_eq!(info.codename, Some("nest".to_string()));
     }
 
+    #[test]
+    fn based_on_id_like() {
+        let data: [(&[&str], Type, Option<Type>); 6] = [
+            (&[], Type::Linux, None),
+            (&["debian"], Type::Linux, Some(Type::Debian)),
+            (&["ubuntu", "debian"], Type::Pop, Some(Type::Ubuntu)),
+            (
+                &["unknown", "rhel", "fedora"],
+                Type::Linux,
+                Some(Type::RedHatEnterprise),
+            ),
+            (&["arch"], Type::Arch, None),
+            (&["unknown"], Type::Linux, None),
+        ];
+
+        for (id_like, os_type, expected) in &data {
+            assert_eq!(*expected, based_on(id_like, *os_type));
+        }
+    }
+
     #[test]
     fn release_info_debug() {
         dbg!("{:?}", &DISTRIBUTIONS[0]);
@@ -4,10 +4,32 @@ use std::process::Command;
 
 use log::{debug, trace};
 
-use crate::{matcher::Matcher, Info, Type, Version};
+use crate::{
+    matcher::Matcher,
+    report::{describe, Attempt, Outcome, Property},
+    Info, Source, Type, Version,
+};
+
+/// The command line that is run, as shown in the detection report.
+pub const COMMAND: &str = "lsb_release -a";
+
+pub fn get(attempts: &mut Vec<Attempt>) -> Option<Info> {
+    let attempt = |outcome| {
+        Attempt::new(
+            Property::Distribution,
+            Some(Source::LsbRelease),
+            COMMAND,
+            outcome,
+        )
+    };
 
-pub fn get() -> Option<Info> {
-    let release = retrieve()?;
+    let release = match retrieve() {
+        Some(release) => release,
+        None => {
+            attempts.push(attempt(Outcome::NotFound));
+            return None;
+        }
+    };
 
     let version = match release.version.as_deref() {
         Some("rolling") => Version::Rolling(None),
@@ -64,15 +86,21 @@ pub fn get() -> Option<Info> {
         Some("VoidLinux") => Type::Void,
         Some("Zorin") => Type::Zorin,
         // Return None here so file_release::get is then used.
-        _ => return None,
+        _ => {
+            let distribution = release.distribution.unwrap_or_default();
+            attempts.push(attempt(Outcome::Unrecognized(distribution)));
+            return None;
+        }
     };
 
-    Some(Info {
+    let info = Info {
         os_type,
         version,
         codename: release.codename,
         ..Default::default()
-    })
+    };
+    attempts.push(attempt(Outcome::Used(describe(&info))));
+    Some(info)
 }
 
 struct LsbRelease {
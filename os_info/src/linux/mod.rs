@@ -1,21 +1,120 @@
+mod container;
+mod deployment;
 mod file_release;
 mod lsb_release;
+mod os_release;
+mod package_manager;
+mod virtualization;
+mod wsl;
+
+use std::path::Path;
 
 use log::trace;
 
-use crate::{architecture, bitness, Info, Type};
+pub use self::os_release::OsRelease;
+
+use crate::{
+    bitness,
+    package_manager::candidates,
+    report::{Attempt, Outcome, Property},
+    uname::{self, UnameField},
+    Container, DetectionReport, Detector, Info, KernelVersion, Source, Type, Virtualization,
+};
 
 pub fn current_platform() -> Info {
     trace!("linux::current_platform is called");
+    detect(&Detector::new())
+}
+
+pub fn detect(detector: &Detector) -> Info {
+    explain(detector).into_info()
+}
 
-    let mut info = lsb_release::get()
-        .or_else(file_release::get)
-        .unwrap_or_else(|| Info::with_type(Type::Linux));
-    info.bitness = bitness::get();
-    info.architecture = architecture::get();
+pub fn explain(detector: &Detector) -> DetectionReport {
+    trace!("linux::explain is called with {:?}", detector);
+
+    let root = detector.root_path();
+    let is_host = root == Path::new("/");
+    let mut attempts = Vec::new();
+
+    let mut info = None;
+    for source in detector.enabled_sources() {
+        let target = match source {
+            Source::LsbRelease => lsb_release::COMMAND.to_owned(),
+            Source::OsRelease => root.join("etc/os-release").display().to_string(),
+            Source::ReleaseFiles => format!("{}/*-release", root.join("etc").display()),
+            _ => continue,
+        };
+        // The commands describe the running system and not the one under the root.
+        if info.is_some() || (source == Source::LsbRelease && !is_host) {
+            let attempt = Attempt::new(
+                Property::Distribution,
+                Some(source),
+                target,
+                Outcome::Skipped,
+            );
+            attempts.push(attempt);
+            continue;
+        }
+
+        info = match source {
+            Source::LsbRelease => lsb_release::get(&mut attempts),
+            Source::OsRelease => file_release::get_from_os_release(root, &mut attempts),
+            _ => file_release::get_from_release_files(root, &mut attempts),
+        };
+    }
+    let mut info = info.unwrap_or_else(|| Info::with_type(Type::Linux));
+
+    if is_host {
+        info.bitness = bitness::lookup(detector, &mut attempts);
+        info.architecture = uname::lookup(
+            UnameField::Machine,
+            Property::Architecture,
+            detector,
+            &mut attempts,
+        );
+        info.kernel_version = uname::lookup(
+            UnameField::Release,
+            Property::KernelVersion,
+            detector,
+            &mut attempts,
+        )
+        .map(KernelVersion::parse);
+    }
+
+    info.wsl = wsl::get(root);
+
+    if detector.is_enabled(Source::OsRelease) {
+        add_os_release(&mut info, root);
+    }
+
+    info.deployment = deployment::get(info.os_type, info.os_release.as_ref(), root);
+    // The base distribution is only used if the package managers of the detected one aren't known,
+    // so that derivatives such as Bluefin keep their own package managers.
+    let package_type = match info.based_on {
+        Some(based_on) if candidates(info.os_type).is_empty() => based_on,
+        _ => info.os_type,
+    };
+    info.package_managers = package_manager::get(package_type, root);
 
     trace!("Returning {:?}", info);
-    info
+    DetectionReport { info, attempts }
+}
+
+pub fn container(detector: &Detector) -> Option<Container> {
+    container::get(detector.root_path())
+}
+
+pub fn virtualization(detector: &Detector) -> Option<Virtualization> {
+    virtualization::get(detector.root_path())
+}
+
+fn add_os_release(info: &mut Info, root: &Path) {
+    let os_release = os_release::get(root);
+    info.based_on = os_release
+        .as_ref()
+        .and_then(|r| file_release::based_on(&r.id_like(), info.os_type));
+    info.os_release = os_release;
 }
 
 #[cfg(test)]
@@ -77,4 +176,165 @@ mod tests {
             }
         }
     }
+
+    fn from_root(root: &str) -> Info {
+        detect(&Detector::new().root(root))
+    }
+
+    #[test]
+    fn from_root_derivative() {
+        let info = from_root("src/linux/tests/Ubuntu");
+        assert_eq!(Type::Ubuntu, info.os_type());
+        assert_eq!(Some(Type::Debian), info.based_on());
+        assert_eq!(Some("ubuntu"), info.os_release().and_then(OsRelease::id));
+        assert_eq!(crate::Bitness::Unknown, info.bitness());
+        assert_eq!(None, info.architecture());
+        assert_eq!(None, info.kernel_version());
+    }
+
+    #[test]
+    fn from_root_wsl() {
+        let info = from_root("src/linux/tests/WSL2");
+        assert_eq!(Type::Ubuntu, info.os_type());
+        assert_eq!(Some(crate::WslVersion::Wsl2), info.wsl());
+    }
+
+    #[test]
+    fn from_root_package_managers() {
+        let info = from_root("src/linux/tests/Devuan");
+        assert_eq!(&[crate::PackageManager::Apt], info.package_managers());
+    }
+
+    #[test]
+    fn from_root_derivative_package_managers() {
+        let info = from_root("src/linux/tests/PackageManager_Bluefin");
+        assert_eq!(Type::Bluefin, info.os_type());
+        assert_eq!(Some(Type::Fedora), info.based_on());
+        assert_eq!(
+            &[crate::PackageManager::RpmOstree, crate::PackageManager::Dnf],
+            info.package_managers()
+        );
+    }
+
+    #[test]
+    fn from_root_immutable() {
+        let info = from_root("src/linux/tests/Bluefin");
+        assert_eq!(Some(crate::Deployment::Ostree), info.deployment());
+        assert!(info.is_immutable());
+
+        let info = from_root("src/linux/tests/Ubuntu");
+        assert!(!info.is_immutable());
+    }
+
+    #[test]
+    fn from_root_unknown_distribution() {
+        let info = from_root("src/linux/tests/Devuan");
+        assert_eq!(Type::Linux, info.os_type());
+        assert_eq!(Some(Type::Debian), info.based_on());
+        assert_eq!(Some("devuan"), info.os_release().and_then(OsRelease::id));
+    }
+
+    #[test]
+    fn from_root_no_release() {
+        let info = from_root("src/linux/tests/none_no_path");
+        assert_eq!(Info::with_type(Type::Linux), info);
+    }
+
+    #[test]
+    fn sources_order() {
+        let root = "src/linux/tests/OracleLinux";
+
+        let info = detect(&Detector::new().root(root));
+        assert_eq!(Type::OracleLinux, info.os_type());
+
+        let info = detect(
+            &Detector::new()
+                .root(root)
+                .sources(&[Source::ReleaseFiles, Source::OsRelease]),
+        );
+        assert_eq!(Type::RedHatEnterprise, info.os_type());
+    }
+
+    #[test]
+    fn os_release_disabled() {
+        let info = detect(
+            &Detector::new()
+                .root("src/linux/tests/Ubuntu")
+                .disable(Source::OsRelease),
+        );
+        assert_eq!(Info::with_type(Type::Linux), info);
+    }
+
+    #[test]
+    fn explain_from_root() {
+        let report = explain(&Detector::new().root("src/linux/tests/Ubuntu"));
+        assert_eq!(&from_root("src/linux/tests/Ubuntu"), report.info());
+
+        let attempts: Vec<_> = report
+            .attempts()
+            .iter()
+            .map(|a| (a.source(), a.target(), a.outcome().clone()))
+            .collect();
+        assert_eq!(
+            vec![
+                (Some(Source::LsbRelease), "lsb_release -a", Outcome::Skipped),
+                (
+                    Some(Source::OsRelease),
+                    "src/linux/tests/Ubuntu/etc/os-release",
+                    Outcome::Used("Ubuntu 18.10.0 (cosmic)".to_owned()),
+                ),
+                (
+                    Some(Source::ReleaseFiles),
+                    "src/linux/tests/Ubuntu/etc/*-release",
+                    Outcome::Skipped,
+                ),
+            ],
+            attempts
+        );
+        assert_eq!(
+            Some(Source::OsRelease),
+            report
+                .winner(Property::Distribution)
+                .and_then(Attempt::source)
+        );
+    }
+
+    #[test]
+    fn explain_no_release() {
+        let report = explain(&Detector::new().root("src/linux/tests/none_no_path"));
+        assert_eq!(None, report.winner(Property::Distribution));
+        assert!(report
+            .attempts()
+            .iter()
+            .filter(|a| a.source() == Some(Source::ReleaseFiles))
+            .all(|a| a.outcome() == &Outcome::NotFound));
+    }
+
+    #[test]
+    fn explain_host() {
+        let report = explain(&Detector::new().spawn_processes(false));
+        assert_eq!(
+            &detect(&Detector::new().spawn_processes(false)),
+            report.info()
+        );
+        for property in &[
+            Property::Bitness,
+            Property::Architecture,
+            Property::KernelVersion,
+        ] {
+            assert!(report.winner(*property).is_some(), "{}", property);
+        }
+        assert!(report
+            .attempts()
+            .iter()
+            .all(|a| a.source() != Some(Source::LsbRelease) || a.outcome() == &Outcome::Skipped));
+    }
+
+    #[test]
+    fn no_processes() {
+        let info = detect(&Detector::new().spawn_processes(false));
+        assert_ne!(crate::Bitness::Unknown, info.bitness());
+        assert!(info.architecture().is_some());
+        assert!(info.kernel_version().is_some());
+    }
 }
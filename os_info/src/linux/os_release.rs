@@ -0,0 +1,349 @@
+// spell-checker:ignore cosmic, fedora, bluefin, Archaeopteryx, Silverblue
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use log::{trace, warn};
+
+/// Locations of the os-release file relative to the root, in the order they are checked.
+const PATHS: [&str; 2] = ["etc/os-release", "usr/lib/os-release"];
+
+/// Parsed contents of the `os-release` file (<https://www.freedesktop.org/software/systemd/man/latest/os-release.html>).
+///
+/// All keys from the file are preserved, including vendor-specific ones, and values are unquoted
+/// and unescaped following the shell-like syntax described by the specification.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::linux::OsRelease;
+///
+/// let release = OsRelease::parse("ID=ubuntu\nID_LIKE=debian\nPRETTY_NAME=\"Ubuntu 24.04 LTS\"");
+/// assert_eq!(Some("ubuntu"), release.id());
+/// assert_eq!(vec!["debian"], release.id_like());
+/// assert_eq!(Some("Ubuntu 24.04 LTS"), release.pretty_name());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OsRelease {
+    fields: BTreeMap<String, String>,
+}
+
+impl OsRelease {
+    /// Parses the given os-release file contents.
+    ///
+    /// Comments, blank lines and malformed lines are ignored. If a key is present multiple times,
+    /// then the last value is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::linux::OsRelease;
+    ///
+    /// let release = OsRelease::parse("# Comment\nNAME='Some \"Linux\"'\nVERSION=\"1 \\$2\"");
+    /// assert_eq!(Some("Some \"Linux\""), release.name());
+    /// assert_eq!(Some("1 $2"), release.version());
+    /// ```
+    pub fn parse(content: &str) -> Self {
+        let mut fields = BTreeMap::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) if is_valid_key(key) => (key, value),
+                _ => {
+                    trace!("Skipping malformed os-release line: {:?}", line);
+                    continue;
+                }
+            };
+
+            fields.insert(key.to_owned(), unquote(value));
+        }
+
+        Self { fields }
+    }
+
+    /// Returns the value of the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::linux::OsRelease;
+    ///
+    /// let release = OsRelease::parse("X_VENDOR_KEY=value");
+    /// assert_eq!(Some("value"), release.get("X_VENDOR_KEY"));
+    /// assert_eq!(None, release.get("ID"));
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields.get(key).map(String::as_str)
+    }
+
+    /// Returns an iterator over all key/value pairs ordered by key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::linux::OsRelease;
+    ///
+    /// let release = OsRelease::parse("NAME=Linux\nID=linux");
+    /// let keys: Vec<_> = release.iter().map(|(k, _)| k).collect();
+    /// assert_eq!(vec!["ID", "NAME"], keys);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Returns the `NAME` value.
+    pub fn name(&self) -> Option<&str> {
+        self.get("NAME")
+    }
+
+    /// Returns the `ID` value.
+    pub fn id(&self) -> Option<&str> {
+        self.get("ID")
+    }
+
+    /// Returns the space-separated `ID_LIKE` value as a list of identifiers.
+    pub fn id_like(&self) -> Vec<&str> {
+        self.get("ID_LIKE")
+            .map(|v| v.split_whitespace().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the `PRETTY_NAME` value.
+    pub fn pretty_name(&self) -> Option<&str> {
+        self.get("PRETTY_NAME")
+    }
+
+    /// Returns the `VERSION` value.
+    pub fn version(&self) -> Option<&str> {
+        self.get("VERSION")
+    }
+
+    /// Returns the `VERSION_ID` value.
+    pub fn version_id(&self) -> Option<&str> {
+        self.get("VERSION_ID")
+    }
+
+    /// Returns the `VERSION_CODENAME` value.
+    pub fn version_codename(&self) -> Option<&str> {
+        self.get("VERSION_CODENAME")
+    }
+
+    /// Returns the `VARIANT` value.
+    pub fn variant(&self) -> Option<&str> {
+        self.get("VARIANT")
+    }
+
+    /// Returns the `VARIANT_ID` value.
+    pub fn variant_id(&self) -> Option<&str> {
+        self.get("VARIANT_ID")
+    }
+
+    /// Returns the `BUILD_ID` value.
+    pub fn build_id(&self) -> Option<&str> {
+        self.get("BUILD_ID")
+    }
+
+    /// Returns the `IMAGE_ID` value.
+    pub fn image_id(&self) -> Option<&str> {
+        self.get("IMAGE_ID")
+    }
+
+    /// Returns the `IMAGE_VERSION` value.
+    pub fn image_version(&self) -> Option<&str> {
+        self.get("IMAGE_VERSION")
+    }
+
+    /// Returns the `HOME_URL` value.
+    pub fn home_url(&self) -> Option<&str> {
+        self.get("HOME_URL")
+    }
+
+    /// Returns the `SUPPORT_URL` value.
+    pub fn support_url(&self) -> Option<&str> {
+        self.get("SUPPORT_URL")
+    }
+
+    /// Returns the `BUG_REPORT_URL` value.
+    pub fn bug_report_url(&self) -> Option<&str> {
+        self.get("BUG_REPORT_URL")
+    }
+
+    /// Returns the `SUPPORT_END` value (a date in the `YYYY-MM-DD` format).
+    pub fn support_end(&self) -> Option<&str> {
+        self.get("SUPPORT_END")
+    }
+
+    /// Returns the `CPE_NAME` value.
+    pub fn cpe_name(&self) -> Option<&str> {
+        self.get("CPE_NAME")
+    }
+}
+
+pub fn get(root: &Path) -> Option<OsRelease> {
+    retrieve(root)
+}
+
+fn retrieve(root: impl AsRef<Path>) -> Option<OsRelease> {
+    for path in &PATHS {
+        let path = root.as_ref().join(path);
+
+        if !path.exists() {
+            trace!("Path '{}' doesn't exist", path.display());
+            continue;
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(content) => return Some(OsRelease::parse(&content)),
+            Err(e) => {
+                warn!("Unable to read {:?} file: {:?}", &path, e);
+                continue;
+            }
+        }
+    }
+
+    None
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Removes quotes and backslash escapes from the value in the same way as a shell would do.
+fn unquote(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.trim().chars();
+    let mut quote = None;
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (Some('\''), c) => result.push(c),
+            (Some(_), '\\') => match chars.next() {
+                Some(next @ ('"' | '\\' | '$' | '`')) => result.push(next),
+                Some(next) => {
+                    result.push('\\');
+                    result.push(next);
+                }
+                None => result.push('\\'),
+            },
+            (None, '\\') => {
+                if let Some(next) = chars.next() {
+                    result.push(next);
+                }
+            }
+            (_, c) => result.push(c),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unquote_values() {
+        let data = [
+            ("", ""),
+            ("plain", "plain"),
+            ("  padded ", "padded"),
+            ("\"double quoted\"", "double quoted"),
+            ("'single quoted'", "single quoted"),
+            ("\"escaped \\\" \\\\ \\$ \\`\"", "escaped \" \\ $ `"),
+            ("\"kept \\n\"", "kept \\n"),
+            ("'no \\\" escapes'", "no \\\" escapes"),
+            ("unquoted\\ escape", "unquoted escape"),
+            ("\"concat\"'enated'", "concatenated"),
+            ("\"unterminated", "unterminated"),
+        ];
+
+        for (input, expected) in &data {
+            assert_eq!(*expected, unquote(input), "input: {input:?}");
+        }
+    }
+
+    #[test]
+    fn parse_skips_invalid_lines() {
+        let release = OsRelease::parse(
+            "# comment\n\n  \nNO_EQUALS_SIGN\n=value\nBAD KEY=1\n  ID=test  \nID_LIKE=\"a  b\"\n",
+        );
+        assert_eq!(
+            vec![("ID", "test"), ("ID_LIKE", "a  b")],
+            release.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(vec!["a", "b"], release.id_like());
+    }
+
+    #[test]
+    fn parse_last_value_wins() {
+        let release = OsRelease::parse("ID=first\nID=second");
+        assert_eq!(Some("second"), release.id());
+    }
+
+    #[test]
+    fn bluefin() {
+        let release = retrieve("src/linux/tests/Bluefin").unwrap();
+        assert_eq!(Some("Bluefin"), release.name());
+        assert_eq!(Some("bluefin"), release.id());
+        assert_eq!(vec!["fedora"], release.id_like());
+        assert_eq!(
+            Some("Bluefin (Version: 41.20250119.1 / FROM Fedora Silverblue 41)"),
+            release.pretty_name()
+        );
+        assert_eq!(Some("41.20250119.1 (Silverblue)"), release.version());
+        assert_eq!(Some("41"), release.version_id());
+        assert_eq!(Some("Archaeopteryx"), release.version_codename());
+        assert_eq!(Some("Silverblue"), release.variant());
+        assert_eq!(Some("bluefin-dx"), release.variant_id());
+        assert_eq!(Some("36c6eef"), release.build_id());
+        assert_eq!(Some("bluefin-dx"), release.image_id());
+        assert_eq!(Some("41.20250119.1"), release.image_version());
+        assert_eq!(Some("https://projectbluefin.io"), release.home_url());
+        assert_eq!(
+            Some("https://github.com/ublue-os/bluefin/issues/"),
+            release.support_url()
+        );
+        assert_eq!(
+            Some("https://github.com/ublue-os/bluefin/issues/"),
+            release.bug_report_url()
+        );
+        assert_eq!(Some("2025-12-15"), release.support_end());
+        assert_eq!(Some("cpe:/o:universal-blue:bluefin:41"), release.cpe_name());
+        assert_eq!(Some("41.20250119.1"), release.get("OSTREE_VERSION"));
+    }
+
+    #[test]
+    fn ubuntu() {
+        let release = retrieve("src/linux/tests/Ubuntu").unwrap();
+        assert_eq!(Some("ubuntu"), release.id());
+        assert_eq!(vec!["debian"], release.id_like());
+        assert_eq!(Some("cosmic"), release.version_codename());
+        assert_eq!(Some("cosmic"), release.get("UBUNTU_CODENAME"));
+        assert_eq!(None, release.variant_id());
+    }
+
+    #[test]
+    fn usr_lib_fallback() {
+        let release = retrieve("src/linux/tests/usr_lib_os_release").unwrap();
+        assert_eq!(Some("test"), release.id());
+        assert_eq!(
+            Some("Vendor \"quoted\" value"),
+            release.get("X_VENDOR_FIELD")
+        );
+    }
+
+    #[test]
+    fn none_no_path() {
+        assert_eq!(None, retrieve("src/linux/tests/none_no_path"));
+    }
+}
@@ -0,0 +1,148 @@
+// spell-checker:ignore eopkg, linuxbrew, tdnf, xbps, zypper
+
+use std::path::Path;
+
+use log::trace;
+
+use crate::{package_manager::candidates, PackageManager, Type};
+
+/// Package managers probed when the distribution isn't known.
+const LINUX: [PackageManager; 11] = [
+    PackageManager::Apt,
+    PackageManager::Dnf,
+    PackageManager::Yum,
+    PackageManager::Tdnf,
+    PackageManager::Zypper,
+    PackageManager::Pacman,
+    PackageManager::Apk,
+    PackageManager::Xbps,
+    PackageManager::Emerge,
+    PackageManager::Eopkg,
+    PackageManager::Nix,
+];
+
+/// Package managers that can be installed in addition to the distribution one.
+const SECONDARY: [PackageManager; 2] = [PackageManager::Nix, PackageManager::Brew];
+
+/// Ranks the package managers of the given operating system by looking at the executables
+/// installed under `root`.
+///
+/// The expected package managers that are installed come first, followed by the secondary ones.
+/// If none of the expected ones is found, all of them are returned in the preferred order.
+pub fn get(os_type: Type, root: &Path) -> Vec<PackageManager> {
+    let candidates = candidates(os_type);
+    let probed: &[PackageManager] = if os_type == Type::Linux {
+        &LINUX
+    } else {
+        candidates
+    };
+
+    let mut managers: Vec<_> = probed
+        .iter()
+        .copied()
+        .filter(|&m| is_installed(m, root))
+        .collect();
+    if managers.is_empty() {
+        managers.extend_from_slice(candidates);
+    }
+    for &manager in &SECONDARY {
+        if !managers.contains(&manager) && is_installed(manager, root) {
+            managers.push(manager);
+        }
+    }
+
+    trace!("Detected {:?} package managers under {:?}", managers, root);
+    managers
+}
+
+fn is_installed(manager: PackageManager, root: &Path) -> bool {
+    paths(manager).iter().any(|path| root.join(path).exists())
+}
+
+fn paths(manager: PackageManager) -> &'static [&'static str] {
+    match manager {
+        PackageManager::Apt => &["usr/bin/apt-get"],
+        PackageManager::Dnf => &["usr/bin/dnf", "usr/bin/dnf5"],
+        PackageManager::Yum => &["usr/bin/yum"],
+        PackageManager::Tdnf => &["usr/bin/tdnf"],
+        PackageManager::RpmOstree => &["usr/bin/rpm-ostree"],
+        PackageManager::Zypper => &["usr/bin/zypper"],
+        PackageManager::Pacman => &["usr/bin/pacman"],
+        PackageManager::Apk => &["sbin/apk", "usr/bin/apk"],
+        PackageManager::Xbps => &["usr/bin/xbps-install"],
+        PackageManager::Emerge => &["usr/bin/emerge"],
+        PackageManager::Eopkg => &["usr/bin/eopkg"],
+        PackageManager::Nix => &["nix/var/nix", "run/current-system/sw/bin/nix"],
+        PackageManager::Brew => &["home/linuxbrew/.linuxbrew/bin/brew"],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn centos_7() {
+        let root = Path::new("src/linux/tests/PackageManager_CentOS7");
+        assert_eq!(vec![PackageManager::Yum], get(Type::CentOS, root));
+    }
+
+    #[test]
+    fn fedora() {
+        let root = Path::new("src/linux/tests/PackageManager_Fedora");
+        assert_eq!(
+            vec![PackageManager::Dnf, PackageManager::Yum],
+            get(Type::Fedora, root)
+        );
+    }
+
+    #[test]
+    fn bluefin() {
+        let root = Path::new("src/linux/tests/PackageManager_Bluefin");
+        assert_eq!(
+            vec![PackageManager::RpmOstree, PackageManager::Dnf],
+            get(Type::Bluefin, root)
+        );
+    }
+
+    #[test]
+    fn secondary() {
+        let root = Path::new("src/linux/tests/PackageManager_Nix");
+        assert_eq!(
+            vec![PackageManager::Apt, PackageManager::Nix],
+            get(Type::Ubuntu, root)
+        );
+    }
+
+    #[test]
+    fn unknown_distribution() {
+        let root = Path::new("src/linux/tests/PackageManager_Void");
+        assert_eq!(vec![PackageManager::Xbps], get(Type::Linux, root));
+    }
+
+    #[test]
+    fn unknown_distribution_secondary() {
+        let root = Path::new("src/linux/tests/PackageManager_Nix");
+        assert_eq!(
+            vec![PackageManager::Apt, PackageManager::Nix],
+            get(Type::Linux, root)
+        );
+    }
+
+    #[test]
+    fn not_installed() {
+        let root = Path::new("src/linux/tests/none_no_path");
+        assert_eq!(
+            vec![PackageManager::Dnf, PackageManager::Yum],
+            get(Type::Fedora, root)
+        );
+    }
+
+    #[test]
+    fn unknown_not_installed() {
+        let root = Path::new("src/linux/tests/none_no_path");
+        assert_eq!(Vec::<PackageManager>::new(), get(Type::Linux, root));
+    }
+}
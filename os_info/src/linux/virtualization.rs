@@ -0,0 +1,210 @@
+// spell-checker:ignore bhyve, cpuinfo, innotek, kubevirt, xen
+
+use std::{fs, path::Path};
+
+use log::trace;
+
+use crate::Virtualization;
+
+/// DMI attributes that can contain the hypervisor name, in the order they are checked.
+const DMI_FILES: [&str; 5] = [
+    "product_name",
+    "sys_vendor",
+    "board_vendor",
+    "bios_vendor",
+    "product_version",
+];
+
+/// Prefixes of the DMI attribute values set by hypervisors.
+const DMI_VENDORS: [(&str, Virtualization); 15] = [
+    ("KVM", Virtualization::Kvm),
+    ("OpenStack", Virtualization::Kvm),
+    ("KubeVirt", Virtualization::Kvm),
+    ("Amazon EC2", Virtualization::Amazon),
+    ("QEMU", Virtualization::Qemu),
+    ("VMware", Virtualization::Vmware),
+    ("VMW", Virtualization::Vmware),
+    ("innotek GmbH", Virtualization::VirtualBox),
+    ("VirtualBox", Virtualization::VirtualBox),
+    ("Xen", Virtualization::Xen),
+    ("Firecracker", Virtualization::Firecracker),
+    ("Cloud Hypervisor", Virtualization::CloudHypervisor),
+    // Chromebooks also have the Google vendor, so the product name must be matched.
+    ("Google Compute Engine", Virtualization::Google),
+    ("Parallels", Virtualization::Parallels),
+    ("BHYVE", Virtualization::Bhyve),
+];
+
+/// Detects the hypervisor by looking at the files under `root`.
+///
+/// Returns `None` if neither DMI data nor CPU flags are available, so it isn't possible to tell
+/// whether the system is virtualized.
+pub fn get(root: &Path) -> Option<Virtualization> {
+    let virtualization = dmi(root)
+        .or_else(|| hypervisor_type(root))
+        .or_else(|| cpu_flags(root));
+
+    trace!(
+        "Detected {:?} virtualization under {:?}",
+        virtualization,
+        root
+    );
+    virtualization
+}
+
+fn dmi(root: &Path) -> Option<Virtualization> {
+    let value = |file: &str| read(root, &format!("sys/class/dmi/id/{file}"));
+
+    // Microsoft is also the vendor of the Surface devices, so the product name must be checked.
+    if value("sys_vendor").as_deref() == Some("Microsoft Corporation")
+        && value("product_name").as_deref() == Some("Virtual Machine")
+    {
+        return Some(Virtualization::HyperV);
+    }
+
+    DMI_FILES
+        .iter()
+        .filter_map(|file| value(file))
+        .find_map(|value| {
+            DMI_VENDORS
+                .iter()
+                .find(|(prefix, _)| value.starts_with(prefix))
+                .map(|&(_, virtualization)| virtualization)
+        })
+}
+
+fn hypervisor_type(root: &Path) -> Option<Virtualization> {
+    if read(root, "sys/hypervisor/type")? != "xen" {
+        return Some(Virtualization::Unknown);
+    }
+
+    // The privileged Xen domain (dom0) has direct access to the hardware. Its kernel still reports
+    // the `hypervisor` CPU flag, so the result must not fall through to `cpu_flags`.
+    let is_dom0 = read(root, "proc/xen/capabilities")
+        .map(|c| c.contains("control_d"))
+        .unwrap_or(false);
+    if is_dom0 {
+        Some(Virtualization::BareMetal)
+    } else {
+        Some(Virtualization::Xen)
+    }
+}
+
+/// The `hypervisor` CPU flag is only reported on x86, so bare metal can't be detected on other
+/// architectures using it.
+fn cpu_flags(root: &Path) -> Option<Virtualization> {
+    let cpuinfo = read(root, "proc/cpuinfo")?;
+    let flags = cpuinfo
+        .lines()
+        .find(|line| line.starts_with("flags"))?
+        .split_once(':')?
+        .1;
+
+    if flags.split_whitespace().any(|flag| flag == "hypervisor") {
+        Some(Virtualization::Unknown)
+    } else {
+        Some(Virtualization::BareMetal)
+    }
+}
+
+fn read(root: &Path, path: &str) -> Option<String> {
+    fs::read_to_string(root.join(path))
+        .ok()
+        .map(|content| content.trim().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn bare_metal() {
+        let root = Path::new("src/linux/tests/Virtualization_BareMetal");
+        assert_eq!(Some(Virtualization::BareMetal), get(root));
+    }
+
+    #[test]
+    fn kvm() {
+        let root = Path::new("src/linux/tests/Virtualization_Kvm");
+        assert_eq!(Some(Virtualization::Kvm), get(root));
+    }
+
+    #[test]
+    fn qemu() {
+        let root = Path::new("src/linux/tests/Virtualization_Qemu");
+        assert_eq!(Some(Virtualization::Qemu), get(root));
+    }
+
+    #[test]
+    fn vmware() {
+        let root = Path::new("src/linux/tests/Virtualization_Vmware");
+        assert_eq!(Some(Virtualization::Vmware), get(root));
+    }
+
+    #[test]
+    fn hyper_v() {
+        let root = Path::new("src/linux/tests/Virtualization_HyperV");
+        assert_eq!(Some(Virtualization::HyperV), get(root));
+    }
+
+    #[test]
+    fn surface() {
+        let root = Path::new("src/linux/tests/Virtualization_Surface");
+        assert_eq!(Some(Virtualization::BareMetal), get(root));
+    }
+
+    #[test]
+    fn virtualbox() {
+        let root = Path::new("src/linux/tests/Virtualization_VirtualBox");
+        assert_eq!(Some(Virtualization::VirtualBox), get(root));
+    }
+
+    #[test]
+    fn xen() {
+        let root = Path::new("src/linux/tests/Virtualization_Xen");
+        assert_eq!(Some(Virtualization::Xen), get(root));
+    }
+
+    #[test]
+    fn xen_dom0() {
+        let root = Path::new("src/linux/tests/Virtualization_XenDom0");
+        assert_eq!(Some(Virtualization::BareMetal), get(root));
+    }
+
+    #[test]
+    fn cloud_hypervisor() {
+        let root = Path::new("src/linux/tests/Virtualization_CloudHypervisor");
+        assert_eq!(Some(Virtualization::CloudHypervisor), get(root));
+    }
+
+    #[test]
+    fn amazon() {
+        let root = Path::new("src/linux/tests/Virtualization_Amazon");
+        assert_eq!(Some(Virtualization::Amazon), get(root));
+    }
+
+    #[test]
+    fn google() {
+        let root = Path::new("src/linux/tests/Virtualization_Google");
+        assert_eq!(Some(Virtualization::Google), get(root));
+    }
+
+    #[test]
+    fn chromebook() {
+        let root = Path::new("src/linux/tests/Virtualization_Chromebook");
+        assert_eq!(None, get(root));
+    }
+
+    #[test]
+    fn unknown() {
+        let root = Path::new("src/linux/tests/Virtualization_Unknown");
+        assert_eq!(Some(Virtualization::Unknown), get(root));
+    }
+
+    #[test]
+    fn no_path() {
+        let root = Path::new("src/linux/tests/none_no_path");
+        assert_eq!(None, get(root));
+    }
+}
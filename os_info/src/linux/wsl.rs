@@ -0,0 +1,94 @@
+// spell-checker:ignore binfmt, osrelease, WSLInterop
+
+use std::{fs, path::Path};
+
+use log::trace;
+
+use crate::WslVersion;
+
+/// Detects the Windows Subsystem for Linux by looking at the files under `root`.
+pub fn get(root: &Path) -> Option<WslVersion> {
+    let version = kernel_release(root).or_else(|| {
+        // WSL1 always reports the Microsoft kernel release, so another kernel means that a custom
+        // one is used in WSL2. The `WSL_DISTRO_NAME` environment variable isn't checked, because both
+        // versions set it and it can be copied into environments that don't run under WSL.
+        if has_interop(root) {
+            Some(WslVersion::Wsl2)
+        } else {
+            None
+        }
+    });
+
+    trace!("Detected {:?} under {:?}", version, root);
+    version
+}
+
+/// WSL1 kernel releases look like `4.4.0-19041-Microsoft`, while the WSL2 ones are like
+/// `5.15.153.1-microsoft-standard-WSL2` or `4.19.128-microsoft-standard`.
+fn kernel_release(root: &Path) -> Option<WslVersion> {
+    let release = fs::read_to_string(root.join("proc/sys/kernel/osrelease"))
+        .ok()?
+        .to_lowercase();
+
+    if release.contains("wsl2") || release.contains("microsoft-standard") {
+        Some(WslVersion::Wsl2)
+    } else if release.contains("microsoft") {
+        Some(WslVersion::Wsl1)
+    } else {
+        None
+    }
+}
+
+fn has_interop(root: &Path) -> bool {
+    ["WSLInterop", "WSLInterop-late"]
+        .iter()
+        .any(|name| root.join("proc/sys/fs/binfmt_misc").join(name).exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn wsl1() {
+        let root = Path::new("src/linux/tests/WSL1");
+        assert_eq!(Some(WslVersion::Wsl1), get(root));
+    }
+
+    #[test]
+    fn wsl2() {
+        let root = Path::new("src/linux/tests/WSL2");
+        assert_eq!(Some(WslVersion::Wsl2), get(root));
+    }
+
+    #[test]
+    fn wsl2_legacy() {
+        let root = Path::new("src/linux/tests/WSL2_Legacy");
+        assert_eq!(Some(WslVersion::Wsl2), get(root));
+    }
+
+    #[test]
+    fn wsl2_custom_kernel() {
+        let root = Path::new("src/linux/tests/WSL2_CustomKernel");
+        assert_eq!(Some(WslVersion::Wsl2), get(root));
+    }
+
+    #[test]
+    fn wsl_distro_name_only() {
+        let root = Path::new("src/linux/tests/WSL_DistroName");
+        assert_eq!(None, get(root));
+    }
+
+    #[test]
+    fn not_wsl() {
+        let root = Path::new("src/linux/tests/Ubuntu");
+        assert_eq!(None, get(root));
+    }
+
+    #[test]
+    fn no_path() {
+        let root = Path::new("src/linux/tests/none_no_path");
+        assert_eq!(None, get(root));
+    }
+}
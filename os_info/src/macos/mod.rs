@@ -2,47 +2,85 @@ use std::process::Command;
 
 use log::{trace, warn};
 
-use crate::{architecture, bitness, matcher::Matcher, Info, Type, Version};
+use crate::{
+    bitness,
+    matcher::Matcher,
+    report::Property,
+    uname::{self, UnameField},
+    DetectionReport, Detector, Info, KernelVersion, Type, Version,
+};
 
 pub fn current_platform() -> Info {
     trace!("macos::current_platform is called");
+    explain(&Detector::new()).into_info()
+}
+
+pub fn explain(detector: &Detector) -> DetectionReport {
+    trace!("macos::explain is called with {:?}", detector);
+    let mut attempts = Vec::new();
 
-    let architecture = architecture::get();
-    let bits = architecture
-        .as_deref()
-        .map(|arch| match arch {
-            "arm64" | "x86_64" => bitness::Bitness::X64,
-            "i386" => bitness::Bitness::X32,
-            _ => bitness::get(),
-        })
-        .unwrap_or_else(bitness::get);
+    let architecture = uname::lookup(
+        UnameField::Machine,
+        Property::Architecture,
+        detector,
+        &mut attempts,
+    );
+    let bits = match architecture.as_deref() {
+        Some("arm64" | "x86_64") => bitness::Bitness::X64,
+        Some("i386") => bitness::Bitness::X32,
+        _ => bitness::lookup(detector, &mut attempts),
+    };
 
     let info = Info {
         os_type: Type::Macos,
         version: version(),
         bitness: bits,
         architecture,
+        kernel_version: uname::lookup(
+            UnameField::Release,
+            Property::KernelVersion,
+            detector,
+            &mut attempts,
+        )
+        .map(KernelVersion::parse),
         ..Default::default()
     };
     trace!("Returning {:?}", info);
-    info
+    DetectionReport { info, attempts }
 }
 
 fn version() -> Version {
     match product_version() {
         None => Version::Unknown,
-        Some(val) => Version::from_string(val),
+        Some(val) => with_build_version(Version::from_string(val), build_version().as_deref()),
     }
 }
 
+/// Marks the version as a beta if the build version says so.
+fn with_build_version(version: Version, build: Option<&str>) -> Version {
+    match build {
+        Some(build) if is_beta_build(build) => version.with_pre_release("beta"),
+        _ => version,
+    }
+}
+
+/// Beta builds end with a lowercase letter, for example `19A546d`. Rapid Security Responses also
+/// end with a letter (`22F770820d`), but have much longer build numbers.
+fn is_beta_build(build: &str) -> bool {
+    let mut chars = build.chars().rev();
+    let is_suffixed = chars.next().map_or(false, |c| c.is_ascii_lowercase());
+    let number = chars.take_while(char::is_ascii_digit).count();
+    is_suffixed && (1..=4).contains(&number)
+}
+
 #[allow(unsafe_code)]
-fn product_version_from_file() -> Option<String> {
+fn system_version_from_file(key: &objc2_foundation::NSString) -> Option<String> {
     use objc2_foundation::NSData;
     use objc2_foundation::NSDictionary;
     use objc2_foundation::NSPropertyListFormat;
     use objc2_foundation::NSPropertyListMutabilityOptions;
     use objc2_foundation::NSPropertyListSerialization;
-    use objc2_foundation::{ns_string, NSString};
+    use objc2_foundation::NSString;
 
     let buffer = std::fs::read("/System/Library/CoreServices/SystemVersion.plist");
     if let Err(ref e) = buffer {
@@ -73,23 +111,23 @@ fn product_version_from_file() -> Option<String> {
         return None;
     }
 
-    let product_version = dict?.objectForKey(ns_string!("ProductVersion"));
-    if product_version.is_none() {
-        warn!("Failed to get ProductVersion from NSDictionary");
+    let value = dict?.objectForKey(key);
+    if value.is_none() {
+        warn!("Failed to get {key} from NSDictionary");
         return None;
     }
-    let product_version = product_version?
-        .downcast_ref::<NSString>()
-        .map(NSString::to_string);
-    if product_version.is_none() {
-        warn!("Failed to downcast ProductVersion to NSString");
+    let value = value?.downcast_ref::<NSString>().map(NSString::to_string);
+    if value.is_none() {
+        warn!("Failed to downcast {key} to NSString");
         return None;
     }
-    product_version
+    value
 }
 
 fn product_version() -> Option<String> {
-    if let Some(version) = product_version_from_file() {
+    use objc2_foundation::ns_string;
+
+    if let Some(version) = system_version_from_file(ns_string!("ProductVersion")) {
         trace!("ProductVersion from SystemVersion.plist: {version:?}");
         return Some(version);
     }
@@ -107,6 +145,27 @@ fn product_version() -> Option<String> {
     }
 }
 
+fn build_version() -> Option<String> {
+    use objc2_foundation::ns_string;
+
+    if let Some(build) = system_version_from_file(ns_string!("ProductBuildVersion")) {
+        trace!("ProductBuildVersion from SystemVersion.plist: {build:?}");
+        return Some(build);
+    }
+
+    match Command::new("sw_vers").arg("-buildVersion").output() {
+        Ok(val) => {
+            let output = String::from_utf8_lossy(&val.stdout);
+            trace!("sw_vers -buildVersion command returned {:?}", output);
+            Matcher::AllTrimmed.find(&output).filter(|b| !b.is_empty())
+        }
+        Err(e) => {
+            warn!("sw_vers -buildVersion command failed with {:?}", e);
+            None
+        }
+    }
+}
+
 fn parse(sw_vers_output: &str) -> Option<String> {
     Matcher::PrefixedVersion {
         prefix: "ProductVersion:",
@@ -161,6 +220,32 @@ mod tests {
          BuildVersion:	19A546d"
     }
 
+    #[test]
+    fn beta_build() {
+        let data = [
+            ("19A546d", true),
+            ("23A5286g", true),
+            ("14F27", false),
+            ("23H124", false),
+            ("22F770820d", false),
+            ("", false),
+        ];
+
+        for (build, expected) in &data {
+            assert_eq!(*expected, is_beta_build(build), "build: {build}");
+        }
+    }
+
+    #[test]
+    fn beta_version() {
+        let version = with_build_version(Version::from_string("10.15"), Some("19A546d"));
+        assert_eq!(Version::from_string("10.15.0-beta"), version);
+        assert!(version.is_prerelease());
+
+        let version = with_build_version(Version::from_string("10.10.5"), Some("14F27"));
+        assert_eq!(Version::Semantic(10, 10, 5), version);
+    }
+
     #[test]
     fn parse_double_digit_patch_version() {
         let parse_output = parse(sw_vers_output_double_digit_patch_version());
@@ -1,4 +1,14 @@
-use std::fmt::{self, Display, Formatter};
+// spell-checker:ignore almalinux, altlinux, amzn, aosc, archarm, azurelinux, cachyos, centos,
+// spell-checker:ignore endeavouros, hardenedbsd, instantos, linuxmint, mabox, microos, midnightbsd,
+// spell-checker:ignore netbsd, nixos, nobara, openbsd, opencloudos, opensuse, rhel, sled, sles
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::Family;
 
 /// A list of supported operating system types.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -138,6 +148,344 @@ pub enum Type {
     Windows,
 }
 
+/// All operating system types, used to look up the type by its name.
+const ALL: [Type; 64] = [
+    Type::AIX,
+    Type::AlmaLinux,
+    Type::Alpaquita,
+    Type::Alpine,
+    Type::ALTLinux,
+    Type::Amazon,
+    Type::Android,
+    Type::AOSC,
+    Type::Arch,
+    Type::AzureLinux,
+    Type::Artix,
+    Type::Bazzite,
+    Type::Bluefin,
+    Type::CachyOS,
+    Type::CentOS,
+    Type::Cygwin,
+    Type::Debian,
+    Type::DragonFly,
+    Type::Elementary,
+    Type::Emscripten,
+    Type::EndeavourOS,
+    Type::Fedora,
+    Type::FreeBSD,
+    Type::Garuda,
+    Type::Gentoo,
+    Type::HardenedBSD,
+    Type::Hurd,
+    Type::Illumos,
+    Type::InstantOS,
+    Type::Ios,
+    Type::Kali,
+    Type::KDENeon,
+    Type::Linux,
+    Type::Mabox,
+    Type::Macos,
+    Type::Manjaro,
+    Type::Mariner,
+    Type::MidnightBSD,
+    Type::Mint,
+    Type::NetBSD,
+    Type::NixOS,
+    Type::Nobara,
+    Type::OpenBSD,
+    Type::OpenCloudOS,
+    Type::openEuler,
+    Type::openSUSE,
+    Type::OracleLinux,
+    Type::Parrot,
+    Type::PikaOS,
+    Type::Pop,
+    Type::Raspbian,
+    Type::Redhat,
+    Type::RedHatEnterprise,
+    Type::Redox,
+    Type::RockyLinux,
+    Type::Solus,
+    Type::SUSE,
+    Type::Ubuntu,
+    Type::Ultramarine,
+    Type::Uos,
+    Type::Void,
+    Type::Zorin,
+    Type::Unknown,
+    Type::Windows,
+];
+
+/// Alternative os-release `ID` values that aren't returned by `Type::os_release_id`.
+const OS_RELEASE_ID_ALIASES: [(&str, Type); 7] = [
+    ("archarm", Type::Arch),
+    ("manjaro-arm", Type::Manjaro),
+    ("opensuse", Type::openSUSE),
+    ("opensuse-microos", Type::openSUSE),
+    ("opensuse-tumbleweed", Type::openSUSE),
+    ("sled", Type::SUSE),
+    ("sles_sap", Type::SUSE),
+];
+
+/// Short names accepted by `Type::from_str` in addition to the display, variant and os-release
+/// names, for example in requirements such as `alma >=9`.
+const NAME_ALIASES: [(&str, Type); 3] = [
+    ("alma", Type::AlmaLinux),
+    ("alt", Type::ALTLinux),
+    ("mac", Type::Macos),
+];
+
+impl Type {
+    /// Returns the operating system type with the given os-release `ID` value. Unlike `from_str`
+    /// the comparison is case-sensitive, as the value is read from the file.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    pub(crate) fn from_os_release_id(id: &str) -> Option<Type> {
+        ALL.iter()
+            .copied()
+            .find(|t| t.os_release_id() == Some(id))
+            .or_else(|| {
+                OS_RELEASE_ID_ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == id)
+                    .map(|&(_, t)| t)
+            })
+    }
+
+    /// Returns the canonical `ID` value from the os-release file of the operating system
+    /// (<https://www.freedesktop.org/software/systemd/man/latest/os-release.html#ID=>).
+    ///
+    /// Returns `None` for the operating systems that don't provide the os-release file, and for
+    /// the generic `Linux` type that doesn't correspond to any distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Type;
+    ///
+    /// assert_eq!(Some("rhel"), Type::RedHatEnterprise.os_release_id());
+    /// assert_eq!(None, Type::Windows.os_release_id());
+    /// ```
+    pub fn os_release_id(self) -> Option<&'static str> {
+        Some(match self {
+            Type::AlmaLinux => "almalinux",
+            Type::Alpaquita => "alpaquita",
+            Type::Alpine => "alpine",
+            Type::ALTLinux => "altlinux",
+            Type::Amazon => "amzn",
+            Type::AOSC => "aosc",
+            Type::Arch => "arch",
+            Type::AzureLinux => "azurelinux",
+            Type::Artix => "artix",
+            Type::Bazzite => "bazzite",
+            Type::Bluefin => "bluefin",
+            Type::CachyOS => "cachyos",
+            Type::CentOS => "centos",
+            Type::Debian => "debian",
+            Type::DragonFly => "dragonfly",
+            Type::Elementary => "elementary",
+            Type::EndeavourOS => "endeavouros",
+            Type::Fedora => "fedora",
+            Type::FreeBSD => "freebsd",
+            Type::Garuda => "garuda",
+            Type::Gentoo => "gentoo",
+            Type::HardenedBSD => "hardenedbsd",
+            Type::InstantOS => "instantos",
+            Type::Kali => "kali",
+            Type::KDENeon => "neon",
+            Type::Mabox => "mabox",
+            Type::Manjaro => "manjaro",
+            Type::Mariner => "mariner",
+            Type::MidnightBSD => "midnightbsd",
+            Type::Mint => "linuxmint",
+            Type::NetBSD => "netbsd",
+            Type::NixOS => "nixos",
+            Type::Nobara => "nobara",
+            Type::OpenBSD => "openbsd",
+            Type::OpenCloudOS => "opencloudos",
+            Type::openEuler => "openEuler",
+            Type::openSUSE => "opensuse-leap",
+            Type::OracleLinux => "ol",
+            Type::Parrot => "parrot",
+            Type::PikaOS => "pika",
+            Type::Pop => "pop",
+            Type::Raspbian => "raspbian",
+            Type::RedHatEnterprise => "rhel",
+            Type::RockyLinux => "rocky",
+            Type::Solus => "solus",
+            Type::SUSE => "sles",
+            Type::Ubuntu => "ubuntu",
+            Type::Ultramarine => "ultramarine",
+            Type::Uos => "Uos",
+            Type::Void => "void",
+            Type::Zorin => "zorin",
+            Type::AIX
+            | Type::Android
+            | Type::Cygwin
+            | Type::Emscripten
+            | Type::Hurd
+            | Type::Illumos
+            | Type::Ios
+            | Type::Linux
+            | Type::Macos
+            | Type::Redhat
+            | Type::Redox
+            | Type::Unknown
+            | Type::Windows => return None,
+        })
+    }
+
+    /// Returns the family the operating system belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Family, Type};
+    ///
+    /// assert_eq!(Family::Debian, Type::Pop.family());
+    /// assert_eq!(Family::RedHat, Type::RockyLinux.family());
+    /// ```
+    pub fn family(self) -> Family {
+        match self {
+            Type::Debian
+            | Type::Elementary
+            | Type::Kali
+            | Type::KDENeon
+            | Type::Mint
+            | Type::Parrot
+            | Type::PikaOS
+            | Type::Pop
+            | Type::Raspbian
+            | Type::Ubuntu
+            | Type::Uos
+            | Type::Zorin => Family::Debian,
+            Type::AlmaLinux
+            | Type::Amazon
+            | Type::Bazzite
+            | Type::Bluefin
+            | Type::CentOS
+            | Type::Fedora
+            | Type::Nobara
+            | Type::OpenCloudOS
+            | Type::OracleLinux
+            | Type::Redhat
+            | Type::RedHatEnterprise
+            | Type::RockyLinux
+            | Type::Ultramarine => Family::RedHat,
+            Type::Arch
+            | Type::Artix
+            | Type::CachyOS
+            | Type::EndeavourOS
+            | Type::Garuda
+            | Type::InstantOS
+            | Type::Mabox
+            | Type::Manjaro => Family::Arch,
+            Type::openSUSE | Type::SUSE => Family::Suse,
+            Type::Gentoo => Family::Gentoo,
+            Type::Alpaquita | Type::Alpine => Family::Alpine,
+            Type::DragonFly
+            | Type::FreeBSD
+            | Type::HardenedBSD
+            | Type::MidnightBSD
+            | Type::NetBSD
+            | Type::OpenBSD => Family::Bsd,
+            Type::Ios | Type::Macos => Family::Apple,
+            Type::Windows => Family::Windows,
+            Type::AIX
+            | Type::ALTLinux
+            | Type::Android
+            | Type::AOSC
+            | Type::AzureLinux
+            | Type::Cygwin
+            | Type::Emscripten
+            | Type::Hurd
+            | Type::Illumos
+            | Type::Linux
+            | Type::Mariner
+            | Type::NixOS
+            | Type::openEuler
+            | Type::Redox
+            | Type::Solus
+            | Type::Unknown
+            | Type::Void => Family::Other,
+        }
+    }
+
+    /// Returns `true` if the operating system is a Linux distribution.
+    ///
+    /// Android isn't considered a Linux distribution despite using the Linux kernel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Type;
+    ///
+    /// assert!(Type::NixOS.is_linux());
+    /// assert!(!Type::FreeBSD.is_linux());
+    /// ```
+    pub fn is_linux(self) -> bool {
+        match self.family() {
+            Family::Debian
+            | Family::RedHat
+            | Family::Arch
+            | Family::Suse
+            | Family::Gentoo
+            | Family::Alpine => true,
+            _ => matches!(
+                self,
+                Type::ALTLinux
+                    | Type::AOSC
+                    | Type::AzureLinux
+                    | Type::Linux
+                    | Type::Mariner
+                    | Type::NixOS
+                    | Type::openEuler
+                    | Type::Solus
+                    | Type::Void
+            ),
+        }
+    }
+
+    /// Returns `true` if the operating system is a BSD descendant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Type;
+    ///
+    /// assert!(Type::OpenBSD.is_bsd());
+    /// assert!(!Type::Macos.is_bsd());
+    /// ```
+    pub fn is_bsd(self) -> bool {
+        self.family() == Family::Bsd
+    }
+
+    /// Returns `true` if the operating system is Unix or Unix-like, including Linux distributions,
+    /// BSD descendants and Apple operating systems.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Type;
+    ///
+    /// assert!(Type::Macos.is_unix());
+    /// assert!(!Type::Windows.is_unix());
+    /// ```
+    pub fn is_unix(self) -> bool {
+        self.is_linux()
+            || self.is_bsd()
+            || matches!(
+                self,
+                Type::AIX
+                    | Type::Android
+                    | Type::Hurd
+                    | Type::Illumos
+                    | Type::Ios
+                    | Type::Macos
+                    | Type::Redox
+            )
+    }
+}
+
 impl Default for Type {
     fn default() -> Self {
         Type::Unknown
@@ -190,6 +538,52 @@ impl Display for Type {
         }
     }
 }
+
+impl FromStr for Type {
+    type Err = ParseTypeError;
+
+    /// Parses the operating system type from its display name (`Arch Linux`), variant name
+    /// (`Arch`), os-release `ID` value (`arch`) or short name (`alma`, `alt` or `mac`). The
+    /// comparison is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        let matches = |t: &Type| {
+            name.eq_ignore_ascii_case(&t.to_string())
+                || name.eq_ignore_ascii_case(&format!("{t:?}"))
+                || t.os_release_id()
+                    .map_or(false, |id| name.eq_ignore_ascii_case(id))
+        };
+
+        ALL.iter()
+            .copied()
+            .find(matches)
+            .or_else(|| {
+                OS_RELEASE_ID_ALIASES
+                    .iter()
+                    .chain(NAME_ALIASES.iter())
+                    .find(|(alias, _)| name.eq_ignore_ascii_case(alias))
+                    .map(|&(_, t)| t)
+            })
+            .ok_or_else(|| ParseTypeError {
+                value: s.to_owned(),
+            })
+    }
+}
+
+/// An error returned when parsing an unknown operating system type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTypeError {
+    value: String,
+}
+
+impl Display for ParseTypeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "unknown operating system type: '{}'", self.value)
+    }
+}
+
+impl Error for ParseTypeError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,4 +666,233 @@ mod tests {
             assert_eq!(&t.to_string(), expected);
         }
     }
+
+    #[test]
+    fn from_str() {
+        let data = [
+            ("Arch Linux", Ok(Type::Arch)),
+            ("arch linux", Ok(Type::Arch)),
+            ("Arch", Ok(Type::Arch)),
+            ("archarm", Ok(Type::Arch)),
+            ("Mac OS", Ok(Type::Macos)),
+            ("macos", Ok(Type::Macos)),
+            ("Pop!_OS", Ok(Type::Pop)),
+            ("pop", Ok(Type::Pop)),
+            ("Red Hat Enterprise Linux", Ok(Type::RedHatEnterprise)),
+            ("rhel", Ok(Type::RedHatEnterprise)),
+            ("amzn", Ok(Type::Amazon)),
+            ("ol", Ok(Type::OracleLinux)),
+            ("opensuse-leap", Ok(Type::openSUSE)),
+            ("opensuse-tumbleweed", Ok(Type::openSUSE)),
+            ("sles_sap", Ok(Type::SUSE)),
+            (" windows ", Ok(Type::Windows)),
+            ("alma", Ok(Type::AlmaLinux)),
+            ("ALT", Ok(Type::ALTLinux)),
+            ("mac", Ok(Type::Macos)),
+            ("Unknown", Ok(Type::Unknown)),
+            (
+                "",
+                Err(ParseTypeError {
+                    value: "".to_owned(),
+                }),
+            ),
+            (
+                "foo",
+                Err(ParseTypeError {
+                    value: "foo".to_owned(),
+                }),
+            ),
+        ];
+
+        for (s, expected) in &data {
+            assert_eq!(*expected, s.parse::<Type>(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn all_types() {
+        // The match has no wildcard, so adding a variant doesn't compile until it's listed here
+        // and in `ALL` at the same position.
+        let position = |t: Type| match t {
+            Type::AIX => 0,
+            Type::AlmaLinux => 1,
+            Type::Alpaquita => 2,
+            Type::Alpine => 3,
+            Type::ALTLinux => 4,
+            Type::Amazon => 5,
+            Type::Android => 6,
+            Type::AOSC => 7,
+            Type::Arch => 8,
+            Type::AzureLinux => 9,
+            Type::Artix => 10,
+            Type::Bazzite => 11,
+            Type::Bluefin => 12,
+            Type::CachyOS => 13,
+            Type::CentOS => 14,
+            Type::Cygwin => 15,
+            Type::Debian => 16,
+            Type::DragonFly => 17,
+            Type::Elementary => 18,
+            Type::Emscripten => 19,
+            Type::EndeavourOS => 20,
+            Type::Fedora => 21,
+            Type::FreeBSD => 22,
+            Type::Garuda => 23,
+            Type::Gentoo => 24,
+            Type::HardenedBSD => 25,
+            Type::Hurd => 26,
+            Type::Illumos => 27,
+            Type::InstantOS => 28,
+            Type::Ios => 29,
+            Type::Kali => 30,
+            Type::KDENeon => 31,
+            Type::Linux => 32,
+            Type::Mabox => 33,
+            Type::Macos => 34,
+            Type::Manjaro => 35,
+            Type::Mariner => 36,
+            Type::MidnightBSD => 37,
+            Type::Mint => 38,
+            Type::NetBSD => 39,
+            Type::NixOS => 40,
+            Type::Nobara => 41,
+            Type::OpenBSD => 42,
+            Type::OpenCloudOS => 43,
+            Type::openEuler => 44,
+            Type::openSUSE => 45,
+            Type::OracleLinux => 46,
+            Type::Parrot => 47,
+            Type::PikaOS => 48,
+            Type::Pop => 49,
+            Type::Raspbian => 50,
+            Type::Redhat => 51,
+            Type::RedHatEnterprise => 52,
+            Type::Redox => 53,
+            Type::RockyLinux => 54,
+            Type::Solus => 55,
+            Type::SUSE => 56,
+            Type::Ubuntu => 57,
+            Type::Ultramarine => 58,
+            Type::Uos => 59,
+            Type::Void => 60,
+            Type::Zorin => 61,
+            Type::Unknown => 62,
+            Type::Windows => 63,
+        };
+
+        for (i, t) in ALL.iter().enumerate() {
+            assert_eq!(i, position(*t), "{t:?}");
+        }
+    }
+
+    #[test]
+    fn from_str_round_trip() {
+        for t in &ALL {
+            assert_eq!(Ok(*t), t.to_string().parse(), "{t:?}");
+            assert_eq!(Ok(*t), format!("{t:?}").parse(), "{t:?}");
+            if let Some(id) = t.os_release_id() {
+                assert_eq!(Ok(*t), id.parse(), "{t:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_os_release_id() {
+        let data = [
+            ("rhel", Some(Type::RedHatEnterprise)),
+            ("manjaro", Some(Type::Manjaro)),
+            ("manjaro-arm", Some(Type::Manjaro)),
+            ("sled", Some(Type::SUSE)),
+            ("Uos", Some(Type::Uos)),
+            ("uos", None),
+            ("alma", None),
+            ("devuan", None),
+            ("linux", None),
+        ];
+
+        for (id, expected) in &data {
+            assert_eq!(*expected, Type::from_os_release_id(id), "{id}");
+        }
+    }
+
+    #[test]
+    fn os_release_id() {
+        let data = [
+            (Type::Amazon, Some("amzn")),
+            (Type::KDENeon, Some("neon")),
+            (Type::Mint, Some("linuxmint")),
+            (Type::openSUSE, Some("opensuse-leap")),
+            (Type::OracleLinux, Some("ol")),
+            (Type::RedHatEnterprise, Some("rhel")),
+            (Type::SUSE, Some("sles")),
+            (Type::Linux, None),
+            (Type::Macos, None),
+            (Type::Unknown, None),
+        ];
+
+        for (t, expected) in &data {
+            assert_eq!(*expected, t.os_release_id(), "{t:?}");
+        }
+    }
+
+    #[test]
+    fn parse_error_display() {
+        let err = "foo".parse::<Type>().unwrap_err();
+        assert_eq!("unknown operating system type: 'foo'", err.to_string());
+    }
+
+    #[test]
+    fn family() {
+        let data = [
+            (Type::Pop, Family::Debian),
+            (Type::Mint, Family::Debian),
+            (Type::Zorin, Family::Debian),
+            (Type::Kali, Family::Debian),
+            (Type::RockyLinux, Family::RedHat),
+            (Type::AlmaLinux, Family::RedHat),
+            (Type::OracleLinux, Family::RedHat),
+            (Type::CentOS, Family::RedHat),
+            (Type::Manjaro, Family::Arch),
+            (Type::EndeavourOS, Family::Arch),
+            (Type::Garuda, Family::Arch),
+            (Type::CachyOS, Family::Arch),
+            (Type::openSUSE, Family::Suse),
+            (Type::Alpine, Family::Alpine),
+            (Type::HardenedBSD, Family::Bsd),
+            (Type::Ios, Family::Apple),
+            (Type::Windows, Family::Windows),
+            (Type::NixOS, Family::Other),
+            (Type::Unknown, Family::Other),
+        ];
+
+        for (t, expected) in &data {
+            assert_eq!(*expected, t.family(), "{t:?}");
+        }
+    }
+
+    #[test]
+    fn is_linux_bsd_unix() {
+        let data = [
+            (Type::Ubuntu, true, false, true),
+            (Type::Fedora, true, false, true),
+            (Type::Void, true, false, true),
+            (Type::Linux, true, false, true),
+            (Type::Android, false, false, true),
+            (Type::FreeBSD, false, true, true),
+            (Type::DragonFly, false, true, true),
+            (Type::Macos, false, false, true),
+            (Type::Illumos, false, false, true),
+            (Type::Hurd, false, false, true),
+            (Type::Windows, false, false, false),
+            (Type::Cygwin, false, false, false),
+            (Type::Emscripten, false, false, false),
+            (Type::Unknown, false, false, false),
+        ];
+
+        for (t, linux, bsd, unix) in &data {
+            assert_eq!(*linux, t.is_linux(), "{t:?}");
+            assert_eq!(*bsd, t.is_bsd(), "{t:?}");
+            assert_eq!(*unix, t.is_unix(), "{t:?}");
+        }
+    }
 }
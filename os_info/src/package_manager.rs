@@ -0,0 +1,145 @@
+// spell-checker:ignore eopkg, pkgin, tdnf, winget, xbps, zypper
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Family, Type};
+
+/// System package manager.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum PackageManager {
+    /// APT (<https://wiki.debian.org/Apt>).
+    Apt,
+    /// DNF (<https://github.com/rpm-software-management/dnf>).
+    Dnf,
+    /// YUM, the predecessor of DNF (<https://en.wikipedia.org/wiki/Yum_(software)>).
+    Yum,
+    /// Tiny DNF used by Azure Linux (<https://github.com/vmware/tdnf>).
+    Tdnf,
+    /// rpm-ostree used by the image based Fedora variants (<https://coreos.github.io/rpm-ostree/>).
+    RpmOstree,
+    /// Zypper (<https://en.opensuse.org/Portal:Zypper>).
+    Zypper,
+    /// pacman (<https://wiki.archlinux.org/title/Pacman>).
+    Pacman,
+    /// Alpine Package Keeper (<https://wiki.alpinelinux.org/wiki/Alpine_Package_Keeper>).
+    Apk,
+    /// X Binary Package System (<https://github.com/void-linux/xbps>).
+    Xbps,
+    /// Portage (<https://wiki.gentoo.org/wiki/Portage>).
+    Emerge,
+    /// eopkg (<https://help.getsol.us/docs/user/package-management/basics>).
+    Eopkg,
+    /// Nix (<https://nixos.org/>).
+    Nix,
+    /// pkg (<https://wiki.freebsd.org/pkg>).
+    Pkg,
+    /// OpenBSD package tools (<https://man.openbsd.org/pkg_add>).
+    PkgAdd,
+    /// pkgin (<https://pkgin.net/>).
+    Pkgin,
+    /// Homebrew (<https://brew.sh/>).
+    Brew,
+    /// Windows Package Manager (<https://learn.microsoft.com/en-us/windows/package-manager/>).
+    Winget,
+}
+
+impl Display for PackageManager {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            PackageManager::Apt => write!(f, "apt"),
+            PackageManager::Dnf => write!(f, "dnf"),
+            PackageManager::Yum => write!(f, "yum"),
+            PackageManager::Tdnf => write!(f, "tdnf"),
+            PackageManager::RpmOstree => write!(f, "rpm-ostree"),
+            PackageManager::Zypper => write!(f, "zypper"),
+            PackageManager::Pacman => write!(f, "pacman"),
+            PackageManager::Apk => write!(f, "apk"),
+            PackageManager::Xbps => write!(f, "xbps"),
+            PackageManager::Emerge => write!(f, "emerge"),
+            PackageManager::Eopkg => write!(f, "eopkg"),
+            PackageManager::Nix => write!(f, "nix"),
+            PackageManager::Pkg => write!(f, "pkg"),
+            PackageManager::PkgAdd => write!(f, "pkg_add"),
+            PackageManager::Pkgin => write!(f, "pkgin"),
+            PackageManager::Brew => write!(f, "brew"),
+            PackageManager::Winget => write!(f, "winget"),
+        }
+    }
+}
+
+/// Returns the package managers the operating system is expected to use, the preferred one first.
+pub(crate) fn candidates(os_type: Type) -> &'static [PackageManager] {
+    use PackageManager::*;
+
+    match os_type {
+        Type::Bazzite | Type::Bluefin => &[RpmOstree, Dnf],
+        Type::AzureLinux | Type::Mariner => &[Tdnf, Dnf],
+        Type::Redhat => &[Yum],
+        Type::openEuler => &[Dnf, Yum],
+        Type::ALTLinux | Type::AOSC => &[Apt],
+        Type::Void => &[Xbps],
+        Type::NixOS => &[Nix],
+        Type::Solus => &[Eopkg],
+        Type::DragonFly | Type::FreeBSD | Type::HardenedBSD => &[Pkg],
+        Type::OpenBSD => &[PkgAdd],
+        Type::NetBSD => &[Pkgin],
+        Type::Macos => &[Brew],
+        _ => match os_type.family() {
+            Family::Debian => &[Apt],
+            Family::RedHat => &[Dnf, Yum],
+            Family::Arch => &[Pacman],
+            Family::Suse => &[Zypper],
+            Family::Gentoo => &[Emerge],
+            Family::Alpine => &[Apk],
+            Family::Windows => &[Winget],
+            _ => &[],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display() {
+        let data = [
+            (PackageManager::Apt, "apt"),
+            (PackageManager::RpmOstree, "rpm-ostree"),
+            (PackageManager::PkgAdd, "pkg_add"),
+            (PackageManager::Winget, "winget"),
+        ];
+
+        for (manager, expected) in &data {
+            assert_eq!(*expected, manager.to_string());
+        }
+    }
+
+    #[test]
+    fn candidates_by_type() {
+        use PackageManager::*;
+
+        let data: [(Type, &[PackageManager]); 12] = [
+            (Type::Pop, &[Apt]),
+            (Type::RockyLinux, &[Dnf, Yum]),
+            (Type::Bluefin, &[RpmOstree, Dnf]),
+            (Type::AzureLinux, &[Tdnf, Dnf]),
+            (Type::EndeavourOS, &[Pacman]),
+            (Type::openSUSE, &[Zypper]),
+            (Type::Alpaquita, &[Apk]),
+            (Type::Void, &[Xbps]),
+            (Type::FreeBSD, &[Pkg]),
+            (Type::Macos, &[Brew]),
+            (Type::Windows, &[Winget]),
+            (Type::Linux, &[]),
+        ];
+
+        for (os_type, expected) in &data {
+            assert_eq!(*expected, candidates(*os_type), "{os_type:?}");
+        }
+    }
+}
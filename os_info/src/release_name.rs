@@ -0,0 +1,156 @@
+// spell-checker:ignore Bionic, bookworm, bullseye, buster, Capitan, Cuttlefish, Dingo, Eoan, forky,
+// spell-checker:ignore Hirsute, Indri, jessie, Kudu, Mantic, Numbat, Oracular, Plucky, Quokka,
+// spell-checker:ignore Tahr, Tahoe, trixie, Vanessa, wheezy, Xenial, Xerus, Yosemite
+
+use crate::{Type, Version};
+
+/// Returns the marketing name of the given operating system release.
+///
+/// If several releases match the version, the most specific one is used, so Linux Mint `21.1` is
+/// Vera and not Vanessa (`21`).
+pub(crate) fn find(os_type: Type, version: &Version) -> Option<&'static str> {
+    names(os_type)
+        .iter()
+        .filter(|(release, _)| version.matches_release(release))
+        .max_by_key(|(release, _)| release.split('.').count())
+        .map(|&(_, name)| name)
+}
+
+fn names(os_type: Type) -> &'static [(&'static str, &'static str)] {
+    match os_type {
+        Type::Debian => &DEBIAN,
+        Type::Macos => &MACOS,
+        Type::Mint => &MINT,
+        Type::Ubuntu => &UBUNTU,
+        Type::Windows => &WINDOWS,
+        _ => &[],
+    }
+}
+
+const DEBIAN: [(&str, &str); 9] = [
+    ("7", "wheezy"),
+    ("8", "jessie"),
+    ("9", "stretch"),
+    ("10", "buster"),
+    ("11", "bullseye"),
+    ("12", "bookworm"),
+    ("13", "trixie"),
+    ("14", "forky"),
+    ("15", "duke"),
+];
+
+const MACOS: [(&str, &str); 22] = [
+    ("10.0", "Cheetah"),
+    ("10.1", "Puma"),
+    ("10.2", "Jaguar"),
+    ("10.3", "Panther"),
+    ("10.4", "Tiger"),
+    ("10.5", "Leopard"),
+    ("10.6", "Snow Leopard"),
+    ("10.7", "Lion"),
+    ("10.8", "Mountain Lion"),
+    ("10.9", "Mavericks"),
+    ("10.10", "Yosemite"),
+    ("10.11", "El Capitan"),
+    ("10.12", "Sierra"),
+    ("10.13", "High Sierra"),
+    ("10.14", "Mojave"),
+    ("10.15", "Catalina"),
+    ("11", "Big Sur"),
+    ("12", "Monterey"),
+    ("13", "Ventura"),
+    ("14", "Sonoma"),
+    ("15", "Sequoia"),
+    ("26", "Tahoe"),
+];
+
+const MINT: [(&str, &str); 10] = [
+    ("20", "Ulyana"),
+    ("20.1", "Ulyssa"),
+    ("20.2", "Uma"),
+    ("20.3", "Una"),
+    ("21", "Vanessa"),
+    ("21.1", "Vera"),
+    ("21.2", "Victoria"),
+    ("21.3", "Virginia"),
+    ("22", "Wilma"),
+    ("22.1", "Xia"),
+];
+
+const UBUNTU: [(&str, &str); 19] = [
+    ("14.04", "Trusty Tahr"),
+    ("16.04", "Xenial Xerus"),
+    ("18.04", "Bionic Beaver"),
+    ("18.10", "Cosmic Cuttlefish"),
+    ("19.04", "Disco Dingo"),
+    ("19.10", "Eoan Ermine"),
+    ("20.04", "Focal Fossa"),
+    ("20.10", "Groovy Gorilla"),
+    ("21.04", "Hirsute Hippo"),
+    ("21.10", "Impish Indri"),
+    ("22.04", "Jammy Jellyfish"),
+    ("22.10", "Kinetic Kudu"),
+    ("23.04", "Lunar Lobster"),
+    ("23.10", "Mantic Minotaur"),
+    ("24.04", "Noble Numbat"),
+    ("24.10", "Oracular Oriole"),
+    ("25.04", "Plucky Puffin"),
+    ("25.10", "Questing Quokka"),
+    ("26.04", "Resolute Raccoon"),
+];
+
+/// Windows feature updates by the build number. Windows 10 and 11 share the `10.0` version.
+const WINDOWS: [(&str, &str); 19] = [
+    ("10.0.10240", "1507"),
+    ("10.0.10586", "1511"),
+    ("10.0.14393", "1607"),
+    ("10.0.15063", "1703"),
+    ("10.0.16299", "1709"),
+    ("10.0.17134", "1803"),
+    ("10.0.17763", "1809"),
+    ("10.0.18362", "1903"),
+    ("10.0.18363", "1909"),
+    ("10.0.19041", "2004"),
+    ("10.0.19042", "20H2"),
+    ("10.0.19043", "21H1"),
+    ("10.0.19044", "21H2"),
+    ("10.0.19045", "22H2"),
+    ("10.0.22000", "21H2"),
+    ("10.0.22621", "22H2"),
+    ("10.0.22631", "23H2"),
+    ("10.0.26100", "24H2"),
+    ("10.0.26200", "25H2"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn find_name() {
+        let data = [
+            (Type::Macos, "14.5.0", Some("Sonoma")),
+            (Type::Macos, "10.15.7", Some("Catalina")),
+            (Type::Macos, "10.1", Some("Puma")),
+            (Type::Macos, "10.10", Some("Yosemite")),
+            (Type::Ubuntu, "24.04", Some("Noble Numbat")),
+            (Type::Ubuntu, "22.04.4", Some("Jammy Jellyfish")),
+            (Type::Ubuntu, "22.05", None),
+            (Type::Debian, "12", Some("bookworm")),
+            (Type::Debian, "11.9", Some("bullseye")),
+            (Type::Windows, "10.0.22631", Some("23H2")),
+            (Type::Windows, "10.0.19045.4291", Some("22H2")),
+            (Type::Windows, "10.0.99999", None),
+            (Type::Mint, "21", Some("Vanessa")),
+            (Type::Mint, "21.1", Some("Vera")),
+            (Type::Fedora, "40", None),
+            (Type::Debian, "", None),
+        ];
+
+        for (os_type, version, expected) in &data {
+            let version = Version::from_string(*version);
+            assert_eq!(*expected, find(*os_type, &version), "{os_type:?} {version}");
+        }
+    }
+}
@@ -0,0 +1,388 @@
+// spell-checker:ignore getconf
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Info, Source, Version};
+
+/// Record of the operating system detection returned by `Detector::explain`.
+///
+/// The report lists every lookup that was performed in the order it was made, along with what it
+/// returned. The lookups are recorded on Linux and the other platforms that use `uname`, such as
+/// macOS and the BSDs. On Windows and the remaining platforms the list is empty.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::Detector;
+///
+/// let report = Detector::new().explain();
+/// for attempt in report.attempts() {
+///     println!("{}: {}", attempt.target(), attempt.outcome());
+/// }
+/// println!("OS information: {}", report.info());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectionReport {
+    pub(crate) info: Info,
+    pub(crate) attempts: Vec<Attempt>,
+}
+
+impl DetectionReport {
+    /// Returns the detected information, the same as returned by `Detector::detect`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// let report = Detector::new().explain();
+    /// println!("OS information: {}", report.info());
+    /// ```
+    pub fn info(&self) -> &Info {
+        &self.info
+    }
+
+    /// Returns the performed lookups in the order they were made.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// let report = Detector::new().explain();
+    /// for attempt in report.attempts() {
+    ///     println!("{}: {}", attempt.target(), attempt.outcome());
+    /// }
+    /// ```
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Returns the lookup whose value was used for the given property, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Property};
+    ///
+    /// let report = Detector::new().explain();
+    /// if let Some(attempt) = report.winner(Property::Distribution) {
+    ///     println!("The distribution is detected from {}", attempt.target());
+    /// }
+    /// ```
+    pub fn winner(&self, property: Property) -> Option<&Attempt> {
+        self.attempts
+            .iter()
+            .find(|a| a.property == property && a.is_used())
+    }
+
+    /// Consumes the report and returns the detected information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// let info = Detector::new().explain().into_info();
+    /// println!("OS information: {info}");
+    /// ```
+    pub fn into_info(self) -> Info {
+        self.info
+    }
+}
+
+/// A single lookup performed during the detection, such as reading a release file or running a
+/// command.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::Detector;
+///
+/// let report = Detector::new().explain();
+/// for attempt in report.attempts().iter().filter(|a| a.is_used()) {
+///     println!("{} is taken from {}", attempt.property(), attempt.target());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    property: Property,
+    source: Option<Source>,
+    target: String,
+    outcome: Outcome,
+}
+
+impl Attempt {
+    #[cfg_attr(
+        not(any(
+            target_os = "aix",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "illumos",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "cygwin",
+            target_os = "hurd",
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn new<S: Into<String>>(
+        property: Property,
+        source: Option<Source>,
+        target: S,
+        outcome: Outcome,
+    ) -> Self {
+        Self {
+            property,
+            source,
+            target: target.into(),
+            outcome,
+        }
+    }
+
+    /// Returns the property that the lookup was made for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// let report = Detector::new().explain();
+    /// for attempt in report.attempts() {
+    ///     println!("{}", attempt.property());
+    /// }
+    /// ```
+    pub fn property(&self) -> Property {
+        self.property
+    }
+
+    /// Returns the configurable source the lookup belongs to, or `None` for the native system
+    /// calls that are always used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Source};
+    ///
+    /// let report = Detector::new().explain();
+    /// let files = report
+    ///     .attempts()
+    ///     .iter()
+    ///     .filter(|a| a.source() == Some(Source::ReleaseFiles))
+    ///     .count();
+    /// println!("{files} release files are checked");
+    /// ```
+    pub fn source(&self) -> Option<Source> {
+        self.source
+    }
+
+    /// Returns what was looked up: a file path, a command line or a system call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// let report = Detector::new().explain();
+    /// for attempt in report.attempts() {
+    ///     println!("{}", attempt.target());
+    /// }
+    /// ```
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Returns the result of the lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Detector, Outcome};
+    ///
+    /// let report = Detector::new().explain();
+    /// for attempt in report.attempts() {
+    ///     if let Outcome::Failed(error) = attempt.outcome() {
+    ///         println!("Unable to read {}: {error}", attempt.target());
+    ///     }
+    /// }
+    /// ```
+    pub fn outcome(&self) -> &Outcome {
+        &self.outcome
+    }
+
+    /// Returns `true` if the value returned by the lookup is used in the detected information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Detector;
+    ///
+    /// let report = Detector::new().explain();
+    /// let used = report.attempts().iter().filter(|a| a.is_used()).count();
+    /// println!("{used} lookups determined the result");
+    /// ```
+    pub fn is_used(&self) -> bool {
+        matches!(self.outcome, Outcome::Used(_))
+    }
+}
+
+/// Part of the operating system information that a lookup determines.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Property {
+    /// Operating system type, version and codename.
+    Distribution,
+    /// See `Bitness`.
+    Bitness,
+    /// Processor architecture.
+    Architecture,
+    /// See `KernelVersion`.
+    KernelVersion,
+}
+
+impl Display for Property {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Property::Distribution => write!(f, "distribution"),
+            Property::Bitness => write!(f, "bitness"),
+            Property::Architecture => write!(f, "architecture"),
+            Property::KernelVersion => write!(f, "kernel version"),
+        }
+    }
+}
+
+/// Result of a lookup.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Outcome {
+    /// The lookup wasn't made, because an earlier one already determined the value or because it
+    /// doesn't apply, for example commands aren't run when the root directory isn't `/`.
+    Skipped,
+    /// The file doesn't exist, or the command or the system call didn't return anything.
+    NotFound,
+    /// The file couldn't be read. Contains the error message.
+    Failed(String),
+    /// The returned value isn't recognized. Contains the value, such as the `lsb_release`
+    /// distributor ID or the first line of the release file.
+    Unrecognized(String),
+    /// The returned value is used. Contains the value.
+    Used(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Outcome::Skipped => write!(f, "skipped"),
+            Outcome::NotFound => write!(f, "not found"),
+            Outcome::Failed(ref error) => write!(f, "failed: {error}"),
+            Outcome::Unrecognized(ref value) if value.is_empty() => write!(f, "unrecognized"),
+            Outcome::Unrecognized(ref value) => write!(f, "unrecognized: {value}"),
+            Outcome::Used(ref value) => write!(f, "used: {value}"),
+        }
+    }
+}
+
+/// Returns the outcome of a lookup that is used if it returned a value.
+#[cfg_attr(
+    not(any(
+        target_os = "aix",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "illumos",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "cygwin",
+        target_os = "hurd",
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn used<T: Display>(value: Option<&T>) -> Outcome {
+    value.map_or(Outcome::NotFound, |v| Outcome::Used(v.to_string()))
+}
+
+/// Describes the distribution detected by a source, such as `Debian 12 (bookworm)`.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) fn describe(info: &Info) -> String {
+    let mut description = info.os_type.to_string();
+    if info.version != Version::Unknown {
+        description.push_str(&format!(" {}", info.version));
+    }
+    if let Some(ref codename) = info.codename {
+        description.push_str(&format!(" ({codename})"));
+    }
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Type;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn winner() {
+        let report = DetectionReport {
+            info: Info::with_type(Type::Debian),
+            attempts: vec![
+                Attempt::new(
+                    Property::Distribution,
+                    Some(Source::LsbRelease),
+                    "lsb_release -a",
+                    Outcome::NotFound,
+                ),
+                Attempt::new(
+                    Property::Distribution,
+                    Some(Source::OsRelease),
+                    "/etc/os-release",
+                    Outcome::Used("Debian".to_owned()),
+                ),
+                Attempt::new(
+                    Property::Bitness,
+                    Some(Source::Getconf),
+                    "getconf LONG_BIT",
+                    Outcome::Failed("error".to_owned()),
+                ),
+            ],
+        };
+
+        assert_eq!(
+            Some("/etc/os-release"),
+            report.winner(Property::Distribution).map(Attempt::target)
+        );
+        assert_eq!(None, report.winner(Property::Bitness));
+        assert_eq!(None, report.winner(Property::Architecture));
+    }
+
+    #[test]
+    fn outcome_display() {
+        let data = [
+            (Outcome::Skipped, "skipped"),
+            (Outcome::NotFound, "not found"),
+            (Outcome::Failed("denied".to_owned()), "failed: denied"),
+            (Outcome::Unrecognized(String::new()), "unrecognized"),
+            (
+                Outcome::Unrecognized("ID=foo".to_owned()),
+                "unrecognized: ID=foo",
+            ),
+            (Outcome::Used("x86_64".to_owned()), "used: x86_64"),
+        ];
+
+        for (outcome, expected) in &data {
+            assert_eq!(*expected, outcome.to_string());
+        }
+    }
+
+    #[test]
+    fn describe_info() {
+        let mut info = Info::with_type(Type::Debian);
+        assert_eq!("Debian", describe(&info));
+
+        info.version = Version::Semantic(12, 0, 0);
+        info.codename = Some("bookworm".to_owned());
+        assert_eq!("Debian 12.0.0 (bookworm)", describe(&info));
+    }
+}
@@ -0,0 +1,545 @@
+// spell-checker:ignore rhel
+
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{Info, Type, Version};
+
+/// Operating system requirement, such as `ubuntu >=20.04, <25` or `windows (Pro) >=10.0.19041`.
+///
+/// A requirement consists of the operating system name, an optional edition in parentheses and a
+/// list of version comparators separated by commas or whitespace. The following comparators are
+/// supported:
+///
+/// - `=1.2` (or just `1.2`) and `!=1.2` compare the version for equality, missing components are
+///   treated as zeros.
+/// - `>1.2`, `>=1.2`, `<1.2` and `<=1.2` compare the version order.
+/// - `^1.2` allows the changes that don't modify the left-most non-zero component
+///   (`>=1.2, <2`).
+/// - `~1.2` allows the patch level changes if the minor version is given (`>=1.2, <1.3`) and the
+///   minor level changes otherwise.
+///
+/// The operating system name is parsed as `Type` (so it can be the variant name or the os-release
+/// identifier, such as `rhel` or `macos`), while the edition must be contained in the edition of
+/// the operating system. See `Version::cmp_semantic` for the details of the version comparison.
+///
+/// # Examples
+///
+/// ```
+/// use os_info::{Info, Requirement, Type};
+///
+/// let requirement: Requirement = "ubuntu >=20.04, <25".parse().unwrap();
+/// assert_eq!(Type::Ubuntu, requirement.os_type());
+///
+/// let info = os_info::get();
+/// if requirement.matches(&info) {
+///     println!("Supported system: {info}");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Requirement {
+    name: String,
+    os_type: Type,
+    edition: Option<String>,
+    comparators: Vec<Comparator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Comparator {
+    op: Op,
+    version: Version,
+    /// The version as it was written in the requirement, used for the display.
+    text: String,
+    /// The number of components given in the requirement, `2` for `^1.2`.
+    precision: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Op {
+    Exact,
+    NotEqual,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Caret,
+    Tilde,
+}
+
+impl Requirement {
+    /// Returns the required operating system type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Requirement, Type};
+    ///
+    /// let requirement: Requirement = "rhel ^8".parse().unwrap();
+    /// assert_eq!(Type::RedHatEnterprise, requirement.os_type());
+    /// ```
+    pub fn os_type(&self) -> Type {
+        self.os_type
+    }
+
+    /// Returns the required operating system edition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Requirement;
+    ///
+    /// let requirement: Requirement = "windows (Pro) >=10.0.19041".parse().unwrap();
+    /// assert_eq!(Some("Pro"), requirement.edition());
+    /// ```
+    pub fn edition(&self) -> Option<&str> {
+        self.edition.as_deref()
+    }
+
+    /// Returns `true` if the given operating system information satisfies the requirement.
+    ///
+    /// A requirement with version comparators never matches a version that can't be compared,
+    /// such as `Version::Unknown` or `Version::Rolling`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::{Info, Requirement};
+    ///
+    /// let requirement: Requirement = "ubuntu >=20.04, <25".parse().unwrap();
+    /// assert!(!requirement.matches(&Info::unknown()));
+    /// ```
+    pub fn matches(&self, info: &Info) -> bool {
+        self.matches_os_type(info.os_type())
+            && self.matches_edition(info.edition())
+            && self.matches_version(info.version())
+    }
+
+    fn matches_os_type(&self, os_type: Type) -> bool {
+        self.os_type == os_type
+    }
+
+    fn matches_edition(&self, edition: Option<&str>) -> bool {
+        match (&self.edition, edition) {
+            (None, _) => true,
+            (Some(required), Some(edition)) => {
+                edition.to_lowercase().contains(&required.to_lowercase())
+            }
+            (Some(_), None) => false,
+        }
+    }
+
+    fn matches_version(&self, version: &Version) -> bool {
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+}
+
+impl Comparator {
+    fn matches(&self, version: &Version) -> bool {
+        let ordering = match version.cmp_semantic(&self.version) {
+            Some(ordering) => ordering,
+            None => return false,
+        };
+
+        match self.op {
+            Op::Exact => ordering == Ordering::Equal,
+            Op::NotEqual => ordering != Ordering::Equal,
+            Op::Greater => ordering == Ordering::Greater,
+            Op::GreaterEq => ordering != Ordering::Less,
+            Op::Less => ordering == Ordering::Less,
+            Op::LessEq => ordering != Ordering::Greater,
+            Op::Caret | Op::Tilde => {
+                ordering != Ordering::Less
+                    && version.cmp_semantic(&self.upper_bound()) == Some(Ordering::Less)
+            }
+        }
+    }
+
+    /// Returns the exclusive upper bound of the caret and tilde comparators.
+    fn upper_bound(&self) -> Version {
+        let mut components = self.version.components().unwrap_or_default();
+        components.truncate(self.precision);
+
+        let index = match self.op {
+            Op::Caret => components
+                .iter()
+                .position(|&c| c != 0)
+                .unwrap_or(components.len() - 1),
+            _ if components.len() > 1 => 1,
+            _ => 0,
+        };
+        components.truncate(index + 1);
+        components[index] += 1;
+
+        Version::from_string(
+            components
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join("."),
+        )
+    }
+}
+
+impl Display for Requirement {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(ref edition) = self.edition {
+            write!(f, " ({edition})")?;
+        }
+        for (i, comparator) in self.comparators.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{separator}{comparator}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Comparator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let op = match self.op {
+            Op::Exact => "=",
+            Op::NotEqual => "!=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Caret => "^",
+            Op::Tilde => "~",
+        };
+        write!(f, "{op}{}", self.text)
+    }
+}
+
+impl FromStr for Requirement {
+    type Err = ParseRequirementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let name_end = s
+            .find(|c: char| c.is_whitespace() || "(<>=!^~,".contains(c))
+            .unwrap_or(s.len());
+        let (name, mut rest) = s.split_at(name_end);
+        if name.is_empty() {
+            return Err(ParseRequirementError::MissingOs);
+        }
+        let os_type = name
+            .parse::<Type>()
+            .map_err(|_| ParseRequirementError::UnknownOs {
+                name: name.to_owned(),
+            })?;
+
+        rest = rest.trim_start();
+        let mut edition = None;
+        if let Some(stripped) = rest.strip_prefix('(') {
+            let end = stripped
+                .find(')')
+                .ok_or(ParseRequirementError::UnclosedEdition)?;
+            let value = stripped[..end].trim();
+            if value.is_empty() {
+                return Err(ParseRequirementError::EmptyEdition);
+            }
+            edition = Some(value.to_owned());
+            rest = &stripped[end + 1..];
+        }
+
+        let comparators = parse_comparators(rest)?;
+
+        Ok(Self {
+            name: name.to_owned(),
+            os_type,
+            edition,
+            comparators,
+        })
+    }
+}
+
+fn parse_comparators(s: &str) -> Result<Vec<Comparator>, ParseRequirementError> {
+    let mut comparators = Vec::new();
+    // An operator separated from its version by whitespace, like `>= 20.04`.
+    let mut pending: Option<(Op, &str)> = None;
+
+    for token in s.split(|c: char| c == ',' || c.is_whitespace()) {
+        if token.is_empty() {
+            continue;
+        }
+
+        let (op, version) = split_op(token);
+        let op = match (pending.take(), op) {
+            (Some((_, previous)), Some(_)) => {
+                return Err(ParseRequirementError::MissingVersion {
+                    comparator: previous.to_owned(),
+                })
+            }
+            (Some((op, _)), None) => Some(op),
+            (None, op) => op,
+        };
+
+        match op {
+            Some(op) if version.is_empty() => pending = Some((op, token)),
+            op => comparators.push(parse_comparator(op.unwrap_or(Op::Exact), version)?),
+        }
+    }
+
+    match pending {
+        Some((_, comparator)) => Err(ParseRequirementError::MissingVersion {
+            comparator: comparator.to_owned(),
+        }),
+        None => Ok(comparators),
+    }
+}
+
+fn split_op(token: &str) -> (Option<Op>, &str) {
+    const OPS: [(&str, Op); 9] = [
+        (">=", Op::GreaterEq),
+        ("<=", Op::LessEq),
+        ("!=", Op::NotEqual),
+        ("==", Op::Exact),
+        (">", Op::Greater),
+        ("<", Op::Less),
+        ("=", Op::Exact),
+        ("^", Op::Caret),
+        ("~", Op::Tilde),
+    ];
+
+    OPS.iter()
+        .find_map(|&(prefix, op)| token.strip_prefix(prefix).map(|v| (Some(op), v)))
+        .unwrap_or((None, token))
+}
+
+fn parse_comparator(op: Op, version: &str) -> Result<Comparator, ParseRequirementError> {
+    let invalid = || ParseRequirementError::InvalidVersion {
+        version: version.to_owned(),
+    };
+
+    let parsed = Version::from_string(version);
+    if parsed.components().is_none() {
+        return Err(invalid());
+    }
+    let precision = version
+        .split(['-', '+'])
+        .next()
+        .unwrap_or_default()
+        .split_terminator('.')
+        .count();
+
+    Ok(Comparator {
+        op,
+        version: parsed,
+        text: version.to_owned(),
+        precision,
+    })
+}
+
+/// An error returned when parsing an invalid requirement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseRequirementError {
+    /// The requirement doesn't start with the operating system name.
+    MissingOs,
+    /// The operating system name isn't known.
+    UnknownOs {
+        /// The given operating system name.
+        name: String,
+    },
+    /// The edition doesn't have the closing parenthesis.
+    UnclosedEdition,
+    /// The edition in parentheses is empty.
+    EmptyEdition,
+    /// The comparison operator isn't followed by a version.
+    MissingVersion {
+        /// The comparator without the version.
+        comparator: String,
+    },
+    /// The version in a comparator isn't numeric.
+    InvalidVersion {
+        /// The given version.
+        version: String,
+    },
+}
+
+impl Display for ParseRequirementError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Self::MissingOs => write!(f, "missing operating system name"),
+            Self::UnknownOs { ref name } => write!(f, "unknown operating system: '{name}'"),
+            Self::UnclosedEdition => write!(f, "missing ')' after the edition"),
+            Self::EmptyEdition => write!(f, "empty edition"),
+            Self::MissingVersion { ref comparator } => {
+                write!(f, "missing version after '{comparator}'")
+            }
+            Self::InvalidVersion { ref version } => write!(f, "invalid version: '{version}'"),
+        }
+    }
+}
+
+impl Error for ParseRequirementError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn info(os_type: Type, version: &str, edition: Option<&str>) -> Info {
+        Info {
+            os_type,
+            version: Version::from_string(version),
+            edition: edition.map(str::to_owned),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let data = [
+            ("ubuntu", Type::Ubuntu, None, "ubuntu"),
+            ("Ubuntu>=22.04", Type::Ubuntu, None, "Ubuntu >=22.04"),
+            (
+                "ubuntu >=20.04, <25",
+                Type::Ubuntu,
+                None,
+                "ubuntu >=20.04, <25",
+            ),
+            ("rhel ^8", Type::RedHatEnterprise, None, "rhel ^8"),
+            (
+                "windows (Pro) >= 10.0.19041",
+                Type::Windows,
+                Some("Pro"),
+                "windows (Pro) >=10.0.19041",
+            ),
+            ("debian 12", Type::Debian, None, "debian =12"),
+            ("amzn >=2", Type::Amazon, None, "amzn >=2"),
+            (
+                "macos ~14.2 !=14.2.1",
+                Type::Macos,
+                None,
+                "macos ~14.2, !=14.2.1",
+            ),
+        ];
+
+        for (s, os_type, edition, display) in &data {
+            let requirement: Requirement = s.parse().unwrap();
+            assert_eq!(*os_type, requirement.os_type(), "requirement: {s}");
+            assert_eq!(*edition, requirement.edition(), "requirement: {s}");
+            assert_eq!(*display, requirement.to_string(), "requirement: {s}");
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let data = [
+            ("", ParseRequirementError::MissingOs),
+            (">=1", ParseRequirementError::MissingOs),
+            (
+                "foo >=1",
+                ParseRequirementError::UnknownOs {
+                    name: "foo".to_owned(),
+                },
+            ),
+            ("windows (Pro >=10", ParseRequirementError::UnclosedEdition),
+            ("windows () >=10", ParseRequirementError::EmptyEdition),
+            (
+                "ubuntu >=",
+                ParseRequirementError::MissingVersion {
+                    comparator: ">=".to_owned(),
+                },
+            ),
+            (
+                "ubuntu >= <25",
+                ParseRequirementError::MissingVersion {
+                    comparator: ">=".to_owned(),
+                },
+            ),
+            (
+                "ubuntu >=twenty",
+                ParseRequirementError::InvalidVersion {
+                    version: "twenty".to_owned(),
+                },
+            ),
+            (
+                "ubuntu >>20",
+                ParseRequirementError::InvalidVersion {
+                    version: ">20".to_owned(),
+                },
+            ),
+        ];
+
+        for (s, expected) in &data {
+            assert_eq!(Err(expected.clone()), s.parse::<Requirement>(), "{s}");
+        }
+    }
+
+    #[test]
+    fn parse_error_display() {
+        let err = "foo".parse::<Requirement>().unwrap_err();
+        assert_eq!("unknown operating system: 'foo'", err.to_string());
+
+        let err = "ubuntu >=".parse::<Requirement>().unwrap_err();
+        assert_eq!("missing version after '>='", err.to_string());
+    }
+
+    #[test]
+    fn matches() {
+        let data = [
+            ("ubuntu", info(Type::Ubuntu, "", None), true),
+            ("ubuntu", info(Type::Debian, "12", None), false),
+            (
+                "ubuntu >=20.04, <25",
+                info(Type::Ubuntu, "22.04", None),
+                true,
+            ),
+            (
+                "ubuntu >=20.04, <25",
+                info(Type::Ubuntu, "25.04", None),
+                false,
+            ),
+            (
+                "ubuntu >=20.04, <25",
+                info(Type::Ubuntu, "18.04", None),
+                false,
+            ),
+            ("ubuntu >=20.04", info(Type::Ubuntu, "", None), false),
+            ("ubuntu <25", info(Type::Ubuntu, "25.04-devel", None), false),
+            ("ubuntu <=24.10", info(Type::Ubuntu, "24.10", None), true),
+            ("ubuntu >24.04", info(Type::Ubuntu, "24.04", None), false),
+            ("debian 12", info(Type::Debian, "12.0", None), true),
+            ("debian !=12", info(Type::Debian, "12", None), false),
+            ("rhel ^8", info(Type::RedHatEnterprise, "8.9", None), true),
+            ("rhel ^8", info(Type::RedHatEnterprise, "9.0", None), false),
+            ("rhel ^8", info(Type::RedHatEnterprise, "7.9", None), false),
+            ("freebsd ^0.5", info(Type::FreeBSD, "0.5.9", None), true),
+            ("freebsd ^0.5", info(Type::FreeBSD, "0.6", None), false),
+            ("macos ~14.2", info(Type::Macos, "14.2.1", None), true),
+            ("macos ~14.2", info(Type::Macos, "14.3", None), false),
+            ("macos ~14", info(Type::Macos, "14.7", None), true),
+            (
+                "windows >=10.0.19041",
+                info(Type::Windows, "10.0.22631", None),
+                true,
+            ),
+            (
+                "windows (pro) >=10.0.19041",
+                info(Type::Windows, "10.0.22631", Some("Windows 11 Pro")),
+                true,
+            ),
+            (
+                "windows (Pro) >=10.0.19041",
+                info(Type::Windows, "10.0.22631", Some("Windows 11 Home")),
+                false,
+            ),
+            (
+                "windows (Pro) >=10.0.19041",
+                info(Type::Windows, "10.0.22631", None),
+                false,
+            ),
+        ];
+
+        for (s, info, expected) in &data {
+            let requirement: Requirement = s.parse().unwrap();
+            assert_eq!(*expected, requirement.matches(info), "{s} for {info}");
+        }
+    }
+}
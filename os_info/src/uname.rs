@@ -3,6 +3,11 @@ use std::process::Command;
 use log::error;
 use nix::sys::utsname::uname as nix_uname;
 
+use crate::{
+    report::{used, Attempt, Outcome, Property},
+    Detector, Source,
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)]
 pub enum UnameField {
@@ -15,7 +20,7 @@ pub enum UnameField {
 }
 
 impl UnameField {
-    fn cli_arg_name(&self) -> &'static str {
+    pub fn cli_arg_name(&self) -> &'static str {
         match self {
             UnameField::Sysname => "-s",
             UnameField::Release => "-r",
@@ -62,10 +67,49 @@ impl UnameField {
     }
 }
 
-pub fn uname(field: UnameField) -> Option<String> {
-    field
-        .get_from_syscall()
-        .or_else(|| uname_cli(field.cli_arg_name()))
+/// Returns the `uname` field from the system call, falling back to the command if
+/// `Source::UnameCommand` is enabled. Every lookup is recorded in `attempts`.
+pub fn lookup(
+    field: UnameField,
+    property: Property,
+    detector: &Detector,
+    attempts: &mut Vec<Attempt>,
+) -> Option<String> {
+    let value = uname_syscall(field);
+    attempts.push(Attempt::new(
+        property,
+        None,
+        format!("uname() {field:?}").to_lowercase(),
+        used(value.as_ref()),
+    ));
+    if value.is_some() {
+        return value;
+    }
+
+    let command = format!("uname {}", field.cli_arg_name());
+    if !detector.is_enabled(Source::UnameCommand) {
+        attempts.push(Attempt::new(
+            property,
+            Some(Source::UnameCommand),
+            command,
+            Outcome::Skipped,
+        ));
+        return None;
+    }
+
+    let value = uname_cli(field.cli_arg_name());
+    attempts.push(Attempt::new(
+        property,
+        Some(Source::UnameCommand),
+        command,
+        used(value.as_ref()),
+    ));
+    value
+}
+
+/// Returns the `uname` field from the system call without running the command.
+pub fn uname_syscall(field: UnameField) -> Option<String> {
+    field.get_from_syscall()
 }
 
 fn uname_cli(arg: &str) -> Option<String> {
@@ -89,10 +133,35 @@ fn uname_cli(arg: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pretty_assertions::assert_eq;
 
     #[test]
-    fn uname_nonempty() {
-        let val = uname(UnameField::Sysname).expect("uname failed");
+    fn lookup_nonempty() {
+        let mut attempts = Vec::new();
+        let val = lookup(
+            UnameField::Sysname,
+            Property::Distribution,
+            &Detector::new(),
+            &mut attempts,
+        )
+        .expect("uname failed");
         assert!(!val.is_empty());
+        assert!(attempts.iter().any(Attempt::is_used));
+    }
+
+    #[test]
+    fn lookup_without_command() {
+        let mut attempts = Vec::new();
+        let detector = Detector::new().spawn_processes(false);
+        let _ = lookup(
+            UnameField::OperatingSystem,
+            Property::Distribution,
+            &detector,
+            &mut attempts,
+        );
+        assert_eq!(
+            Some(&Outcome::Skipped),
+            attempts.last().map(Attempt::outcome)
+        );
     }
 }
@@ -1,17 +1,46 @@
-use std::fmt::{self, Display, Formatter};
+// spell-checker:ignore devel, rawhide
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Labels that mark the beginning of a pre-release suffix (`9.4-beta`, `14.0-RC2`, etc.). The first
+/// identifier of the suffix must be one of them, optionally followed by a number. Other suffixes
+/// (such as `14.0-RELEASE` or `1.0-develop`) aren't pre-releases, so such versions are kept as
+/// `Custom`.
+const PRE_RELEASE_LABELS: [&str; 11] = [
+    "alpha", "beta", "rc", "pre", "preview", "dev", "devel", "snapshot", "nightly", "current",
+    "rawhide",
+];
+
 /// Operating system version.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// Versions are ordered according to the SemVer precedence rules, so for example `9.4-beta` is
+/// less than `9.4`. Versions of different kinds (for example `Rolling` and `Custom`) are ordered
+/// by the variant order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum Version {
     /// Unknown version.
     Unknown,
     /// Semantic version (major.minor.patch).
     Semantic(u64, u64, u64),
+    /// Version with more than three numeric components (for example `10.0.22631.3296`), or with a
+    /// pre-release or build metadata suffix (for example `9.4-beta` or `1.2.3+20240101`).
+    Extended {
+        /// Numeric components of the version.
+        components: Vec<u64>,
+        /// Pre-release identifiers, for example `beta` or `rc.1`.
+        pre_release: Option<String>,
+        /// Build metadata, for example `20240101`.
+        build: Option<String>,
+    },
     /// Rolling version. Optionally contains the release date in the string format.
     Rolling(Option<String>),
     /// Custom version format.
@@ -22,7 +51,9 @@ impl Version {
     /// Constructs `VersionType` from the given string.
     ///
     /// Returns `VersionType::Unknown` if the string is empty. If it can be parsed as a semantic
-    /// version, then `VersionType::Semantic`, otherwise `VersionType::Custom`.
+    /// version, then `VersionType::Semantic` or `VersionType::Extended` if there are more than
+    /// three components or a pre-release or build metadata suffix, otherwise
+    /// `VersionType::Custom`.
     ///
     /// # Examples
     ///
@@ -34,14 +65,221 @@ impl Version {
     ///
     /// let v = Version::from_string("1.2.3");
     /// assert_eq!(Version::Semantic(1, 2, 3), v);
+    ///
+    /// let v = Version::from_string("9.4-beta");
+    /// assert_eq!(
+    ///     Version::Extended {
+    ///         components: vec![9, 4],
+    ///         pre_release: Some("beta".to_owned()),
+    ///         build: None,
+    ///     },
+    ///     v
+    /// );
     /// ```
     pub fn from_string<S: Into<String> + AsRef<str>>(s: S) -> Self {
         if s.as_ref().is_empty() {
-            Self::Unknown
-        } else if let Some((major, minor, patch)) = parse_version(s.as_ref()) {
-            Self::Semantic(major, minor, patch)
-        } else {
-            Self::Custom(s.into())
+            return Self::Unknown;
+        }
+
+        let (core, pre_release, build) = split_suffixes(s.as_ref());
+        match parse_version(core) {
+            Some(components)
+                if components.len() > 3 || pre_release.is_some() || build.is_some() =>
+            {
+                Self::Extended {
+                    components,
+                    pre_release: pre_release.map(str::to_owned),
+                    build: build.map(str::to_owned),
+                }
+            }
+            Some(components) => {
+                let component = |i: usize| components.get(i).copied().unwrap_or(0);
+                Self::Semantic(component(0), component(1), component(2))
+            }
+            None => Self::Custom(s.into()),
+        }
+    }
+
+    /// Returns the numeric components of `Semantic` and `Extended` versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Version;
+    ///
+    /// assert_eq!(Some(vec![1, 2, 0]), Version::Semantic(1, 2, 0).components());
+    /// assert_eq!(
+    ///     Some(vec![10, 0, 22631, 3296]),
+    ///     Version::from_string("10.0.22631.3296").components()
+    /// );
+    /// assert_eq!(None, Version::Custom("custom".to_owned()).components());
+    /// ```
+    pub fn components(&self) -> Option<Vec<u64>> {
+        match *self {
+            Self::Semantic(major, minor, patch) => Some(vec![major, minor, patch]),
+            Self::Extended { ref components, .. } => Some(components.clone()),
+            Self::Unknown | Self::Rolling(_) | Self::Custom(_) => None,
+        }
+    }
+
+    /// Returns the fourth numeric component, such as the update build revision of Windows
+    /// `10.0.22631.3296`. `Semantic` versions have no revision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Version;
+    ///
+    /// assert_eq!(Some(3296), Version::from_string("10.0.22631.3296").revision());
+    /// assert_eq!(None, Version::Semantic(10, 0, 22631).revision());
+    /// ```
+    pub fn revision(&self) -> Option<u64> {
+        match *self {
+            Self::Extended { ref components, .. } => components.get(3).copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the pre-release identifiers if there are any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Version;
+    ///
+    /// assert_eq!(Some("rc.1"), Version::from_string("1.0.0-rc.1").pre_release());
+    /// assert_eq!(None, Version::from_string("1.0.0").pre_release());
+    /// ```
+    pub fn pre_release(&self) -> Option<&str> {
+        match *self {
+            Self::Extended {
+                ref pre_release, ..
+            } => pre_release.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the build metadata if there is any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Version;
+    ///
+    /// assert_eq!(Some("20240101"), Version::from_string("1.2.3+20240101").build());
+    /// assert_eq!(None, Version::from_string("1.2.3").build());
+    /// ```
+    pub fn build(&self) -> Option<&str> {
+        match *self {
+            Self::Extended { ref build, .. } => build.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the version is a pre-release, such as a beta or a development release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Version;
+    ///
+    /// assert!(Version::from_string("9.4-beta").is_prerelease());
+    /// assert!(!Version::from_string("9.4").is_prerelease());
+    /// ```
+    pub fn is_prerelease(&self) -> bool {
+        self.pre_release().is_some()
+    }
+
+    /// Compares versions by their numeric components instead of the variant order used by `Ord`.
+    ///
+    /// The numeric prefix of a `Custom` version (for example `22.04` from `22.04 LTS`) is used for
+    /// the comparison and missing components are treated as zeros, so `22.04` is equal to
+    /// `22.4.0`. A pre-release is less than the corresponding release and the build metadata is
+    /// ignored. Returns `None` if any of the versions doesn't have a numeric part, like `Unknown`
+    /// and `Rolling` versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Version;
+    /// use std::cmp::Ordering;
+    ///
+    /// let version = Version::Custom("10".to_owned());
+    /// assert_eq!(
+    ///     Some(Ordering::Greater),
+    ///     version.cmp_semantic(&Version::Semantic(9, 0, 0))
+    /// );
+    /// assert_eq!(None, version.cmp_semantic(&Version::Rolling(None)));
+    /// ```
+    pub fn cmp_semantic(&self, other: &Version) -> Option<Ordering> {
+        let (lhs, lhs_pre) = self.numeric_parts()?;
+        let (rhs, rhs_pre) = other.numeric_parts()?;
+        Some(cmp_precedence(&lhs, lhs_pre, &rhs, rhs_pre))
+    }
+
+    /// Returns `true` if this version is greater than or equal to the given one. See
+    /// `cmp_semantic` for details.
+    ///
+    /// Returns `false` if the versions can't be compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Version;
+    ///
+    /// let version = Version::Custom("22.04 LTS".to_owned());
+    /// assert!(version.at_least(&Version::Semantic(22, 4, 0)));
+    /// assert!(!version.at_least(&Version::Semantic(24, 4, 0)));
+    /// assert!(!Version::Unknown.at_least(&Version::Semantic(1, 0, 0)));
+    /// ```
+    pub fn at_least(&self, other: &Version) -> bool {
+        self.cmp_semantic(other)
+            .map(|o| o != Ordering::Less)
+            .unwrap_or(false)
+    }
+
+    /// Marks `Semantic` and `Extended` versions as a pre-release with the given label, other
+    /// versions are returned unchanged.
+    #[cfg_attr(not(any(target_os = "linux", target_os = "macos")), allow(dead_code))]
+    pub(crate) fn with_pre_release(self, label: &str) -> Self {
+        match self.components() {
+            Some(components) => Self::Extended {
+                components,
+                pre_release: Some(label.to_owned()),
+                build: self.build().map(str::to_owned),
+            },
+            None => self,
+        }
+    }
+
+    /// Returns `true` if the numeric components start with the components of the given release,
+    /// such as `22.04` for `22.04.4`.
+    pub(crate) fn matches_release(&self, release: &str) -> bool {
+        self.components().map_or(false, |components| {
+            release
+                .split('.')
+                .enumerate()
+                .all(|(i, c)| c.parse().ok() == components.get(i).copied())
+        })
+    }
+
+    /// Returns the numeric components along with the pre-release identifiers, or `None` if there
+    /// are no numeric components.
+    fn numeric_parts(&self) -> Option<(Vec<u64>, Option<&str>)> {
+        match *self {
+            Self::Custom(ref version) => parse_numeric_prefix(version).map(|c| (c, None)),
+            _ => self.components().map(|c| (c, self.pre_release())),
+        }
+    }
+
+    /// Position of the variant in the ordering. `Semantic` and `Extended` versions are compared
+    /// with each other by their precedence.
+    fn rank(&self) -> u8 {
+        match *self {
+            Self::Unknown => 0,
+            Self::Semantic(..) | Self::Extended { .. } => 1,
+            Self::Rolling(_) => 2,
+            Self::Custom(_) => 3,
         }
     }
 }
@@ -52,11 +290,60 @@ impl Default for Version {
     }
 }
 
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Rolling(lhs), Self::Rolling(rhs)) => lhs.cmp(rhs),
+            (Self::Custom(lhs), Self::Custom(rhs)) => lhs.cmp(rhs),
+            _ if self.rank() != other.rank() => self.rank().cmp(&other.rank()),
+            _ => match (self.numeric_parts(), other.numeric_parts()) {
+                (Some((lhs, lhs_pre)), Some((rhs, rhs_pre))) => {
+                    // Versions with the same precedence are ordered by the build metadata and the
+                    // representation to keep the ordering consistent with `Eq`.
+                    cmp_precedence(&lhs, lhs_pre, &rhs, rhs_pre)
+                        .then_with(|| self.build().cmp(&other.build()))
+                        .then_with(|| lhs.len().cmp(&rhs.len()))
+                        .then_with(|| {
+                            let is_extended = |v: &Self| matches!(v, Self::Extended { .. });
+                            is_extended(self).cmp(&is_extended(other))
+                        })
+                }
+                _ => Ordering::Equal,
+            },
+        }
+    }
+}
+
 impl Display for Version {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match *self {
             Self::Unknown => f.write_str("Unknown"),
             Self::Semantic(major, minor, patch) => write!(f, "{major}.{minor}.{patch}"),
+            Self::Extended {
+                ref components,
+                ref pre_release,
+                ref build,
+            } => {
+                for (i, component) in components.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    write!(f, "{component}")?;
+                }
+                if let Some(pre_release) = pre_release {
+                    write!(f, "-{pre_release}")?;
+                }
+                if let Some(build) = build {
+                    write!(f, "+{build}")?;
+                }
+                Ok(())
+            }
             Self::Rolling(ref date) => {
                 let date = match date {
                     Some(date) => format!(" ({date})"),
@@ -69,18 +356,110 @@ impl Display for Version {
     }
 }
 
-fn parse_version(s: &str) -> Option<(u64, u64, u64)> {
-    let mut iter = s.trim().split_terminator('.').fuse();
+fn parse_version(s: &str) -> Option<Vec<u64>> {
+    s.trim()
+        .split_terminator('.')
+        .map(|c| c.parse().ok())
+        .collect::<Option<Vec<_>>>()
+        .filter(|components| !components.is_empty())
+}
+
+/// Splits the version into the numeric part, the pre-release identifiers and the build metadata.
+/// Suffixes that aren't valid are left in the numeric part.
+fn split_suffixes(s: &str) -> (&str, Option<&str>, Option<&str>) {
+    let s = s.trim();
+    let (rest, build) = match s.split_once('+') {
+        Some((rest, build)) if is_valid_identifiers(build) => (rest, Some(build)),
+        _ => (s, None),
+    };
+    match rest.split_once('-') {
+        Some((core, pre_release)) if is_pre_release(pre_release) => {
+            (core, Some(pre_release), build)
+        }
+        _ => (rest, None, build),
+    }
+}
+
+fn is_valid_identifiers(s: &str) -> bool {
+    s.split('.').all(|identifier| {
+        !identifier.is_empty()
+            && identifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+fn is_pre_release(s: &str) -> bool {
+    let label = s
+        .split(['.', '-'])
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(|c: char| c.is_ascii_digit())
+        .to_ascii_lowercase();
+    is_valid_identifiers(s) && PRE_RELEASE_LABELS.contains(&label.as_str())
+}
 
-    let major = iter.next().and_then(|s| s.parse().ok())?;
-    let minor = iter.next().unwrap_or("0").parse().ok()?;
-    let patch = iter.next().unwrap_or("0").parse().ok()?;
+/// Compares versions according to the SemVer precedence rules.
+fn cmp_precedence(
+    lhs: &[u64],
+    lhs_pre: Option<&str>,
+    rhs: &[u64],
+    rhs_pre: Option<&str>,
+) -> Ordering {
+    let len = lhs.len().max(rhs.len());
+    let component = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+    (0..len)
+        .map(|i| component(lhs, i).cmp(&component(rhs, i)))
+        .find(|&o| o != Ordering::Equal)
+        .unwrap_or_else(|| match (lhs_pre, rhs_pre) {
+            (None, None) => Ordering::Equal,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(lhs), Some(rhs)) => cmp_pre_release(lhs, rhs),
+        })
+}
 
-    if iter.next().is_some() {
-        return None;
+/// Numeric identifiers are compared numerically and have lower precedence than alphanumeric ones,
+/// which are compared lexically. A larger set of identifiers has higher precedence.
+fn cmp_pre_release(lhs: &str, rhs: &str) -> Ordering {
+    let mut lhs = lhs.split('.');
+    let mut rhs = rhs.split('.');
+    loop {
+        let ordering = match (lhs.next(), rhs.next()) {
+            (None, None) => return Ordering::Equal,
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(l), Some(r)) => match (l.parse::<u64>(), r.parse::<u64>()) {
+                (Ok(l), Ok(r)) => l.cmp(&r),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => l.cmp(r),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
     }
+}
+
+/// Parses the leading dot-separated numbers, for example `[9, 4]` from `9.4-beta`.
+fn parse_numeric_prefix(s: &str) -> Option<Vec<u64>> {
+    let s = s.trim();
+    let end = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
 
-    Some((major, minor, patch))
+    let components = s[..end]
+        .split('.')
+        .take_while(|c| !c.is_empty())
+        .map(|c| c.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+
+    if components.is_empty() {
+        None
+    } else {
+        Some(components)
+    }
 }
 
 #[cfg(test)]
@@ -88,22 +467,32 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    fn extended(components: &[u64], pre_release: Option<&str>, build: Option<&str>) -> Version {
+        Version::Extended {
+            components: components.to_vec(),
+            pre_release: pre_release.map(str::to_owned),
+            build: build.map(str::to_owned),
+        }
+    }
+
     #[test]
     fn parse_semantic_version() {
         let data = [
             ("", None),
             ("version", None),
-            ("1", Some((1, 0, 0))),
-            ("1.", Some((1, 0, 0))),
-            ("1.2", Some((1, 2, 0))),
-            ("1.2.", Some((1, 2, 0))),
-            ("1.2.3", Some((1, 2, 3))),
-            ("1.2.3.", Some((1, 2, 3))),
-            ("1.2.3.  ", Some((1, 2, 3))),
-            ("   1.2.3.", Some((1, 2, 3))),
-            ("   1.2.3.  ", Some((1, 2, 3))),
-            ("1.2.3.4", None),
-            ("1.2.3.4.5.6.7.8.9", None),
+            ("1", Some(vec![1])),
+            ("1.", Some(vec![1])),
+            ("1.2", Some(vec![1, 2])),
+            ("1.2.", Some(vec![1, 2])),
+            ("1.2.3", Some(vec![1, 2, 3])),
+            ("1.2.3.", Some(vec![1, 2, 3])),
+            ("1.2.3.  ", Some(vec![1, 2, 3])),
+            ("   1.2.3.", Some(vec![1, 2, 3])),
+            ("   1.2.3.  ", Some(vec![1, 2, 3])),
+            ("1.2.3.4", Some(vec![1, 2, 3, 4])),
+            ("1.2.3.4.5.6.7.8.9", Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 9])),
+            ("1..2", None),
+            ("1.2.x", None),
         ];
 
         for (s, expected) in &data {
@@ -117,13 +506,40 @@ mod tests {
         let custom_version = "some version";
         let data = [
             ("", Version::Unknown),
+            ("1", Version::Semantic(1, 0, 0)),
             ("1.2.3", Version::Semantic(1, 2, 3)),
+            (
+                "10.0.22631.3296",
+                extended(&[10, 0, 22631, 3296], None, None),
+            ),
+            ("9.4-beta", extended(&[9, 4], Some("beta"), None)),
+            ("14.0-RC2", extended(&[14, 0], Some("RC2"), None)),
+            ("1.0.0-rc.1", extended(&[1, 0, 0], Some("rc.1"), None)),
+            (
+                "1.2.3+20240101",
+                extended(&[1, 2, 3], None, Some("20240101")),
+            ),
+            (
+                "1.0.0-alpha.1+exp.sha.5114f85",
+                extended(&[1, 0, 0], Some("alpha.1"), Some("exp.sha.5114f85")),
+            ),
+            (
+                "14.0-RELEASE-p3",
+                Version::Custom("14.0-RELEASE-p3".to_owned()),
+            ),
+            ("4.19.112+", Version::Custom("4.19.112+".to_owned())),
+            ("1.0-beta..1", Version::Custom("1.0-beta..1".to_owned())),
+            ("beta-1", Version::Custom("beta-1".to_owned())),
+            ("5.0-beta-2", extended(&[5, 0], Some("beta-2"), None)),
+            ("1.0-devuan", Version::Custom("1.0-devuan".to_owned())),
+            ("1.0-develop-2", Version::Custom("1.0-develop-2".to_owned())),
+            ("2.0-preview", extended(&[2, 0], Some("preview"), None)),
             (custom_version, Version::Custom(custom_version.to_owned())),
         ];
 
         for (s, expected) in &data {
             let version = Version::from_string(*s);
-            assert_eq!(expected, &version);
+            assert_eq!(expected, &version, "version: {s}");
         }
     }
 
@@ -137,6 +553,12 @@ mod tests {
         let data = [
             (Version::Unknown, "Unknown"),
             (Version::Semantic(1, 5, 0), "1.5.0"),
+            (extended(&[5, 11, 0, 7], None, None), "5.11.0.7"),
+            (extended(&[9, 4], Some("beta"), None), "9.4-beta"),
+            (
+                extended(&[1, 0], Some("rc.1"), Some("build.5")),
+                "1.0-rc.1+build.5",
+            ),
             (Version::Rolling(None), "Rolling Release"),
             (
                 Version::Rolling(Some("date".to_owned())),
@@ -148,4 +570,163 @@ mod tests {
             assert_eq!(expected, &version.to_string());
         }
     }
+
+    #[test]
+    fn is_prerelease() {
+        assert!(Version::from_string("9.4-beta").is_prerelease());
+        assert!(Version::from_string("25.04-devel").is_prerelease());
+        assert!(!Version::from_string("9.4").is_prerelease());
+        assert!(!Version::from_string("1.2.3+build").is_prerelease());
+        assert!(!Version::Custom("14.0-RELEASE".to_owned()).is_prerelease());
+    }
+
+    #[test]
+    fn with_pre_release() {
+        assert_eq!(
+            extended(&[42, 0, 0], Some("rawhide"), None),
+            Version::Semantic(42, 0, 0).with_pre_release("rawhide")
+        );
+        assert_eq!(
+            Version::Unknown,
+            Version::Unknown.with_pre_release("rawhide")
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        // Sorted according to the SemVer precedence.
+        let data = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.0.1",
+            "2.0.0",
+            "10.0.0",
+        ];
+
+        for pair in data.windows(2) {
+            let lhs = Version::from_string(pair[0]);
+            let rhs = Version::from_string(pair[1]);
+            assert_eq!(Ordering::Less, lhs.cmp(&rhs), "{lhs} < {rhs}");
+            assert_eq!(Ordering::Greater, rhs.cmp(&lhs), "{rhs} > {lhs}");
+        }
+    }
+
+    #[test]
+    fn ordering_consistent_with_eq() {
+        let data = [
+            Version::Unknown,
+            Version::Semantic(1, 2, 0),
+            extended(&[1, 2], None, None),
+            extended(&[1, 2, 0], None, None),
+            extended(&[1, 2, 0], None, Some("build")),
+            extended(&[1, 2, 0], Some("beta"), None),
+            Version::Rolling(None),
+            Version::Rolling(Some("date".to_owned())),
+            Version::Custom("custom".to_owned()),
+        ];
+
+        for lhs in &data {
+            for rhs in &data {
+                assert_eq!(
+                    lhs == rhs,
+                    lhs.cmp(rhs) == Ordering::Equal,
+                    "{lhs:?} vs {rhs:?}"
+                );
+                assert_eq!(lhs.cmp(rhs), rhs.cmp(lhs).reverse(), "{lhs:?} vs {rhs:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn numeric_prefix() {
+        let data = [
+            ("", None),
+            ("version", None),
+            (".1", None),
+            ("10", Some(vec![10])),
+            ("22.04 LTS", Some(vec![22, 4])),
+            ("9.4-beta", Some(vec![9, 4])),
+            ("10.0.22631.3296", Some(vec![10, 0, 22631, 3296])),
+            ("1..2", Some(vec![1])),
+            ("99999999999999999999999", None),
+        ];
+
+        for (s, expected) in &data {
+            assert_eq!(expected, &parse_numeric_prefix(s), "version: {s}");
+        }
+    }
+
+    #[test]
+    fn cmp_semantic() {
+        let custom = |s: &str| Version::Custom(s.to_owned());
+        let data = [
+            (
+                custom("10"),
+                Version::Semantic(9, 0, 0),
+                Some(Ordering::Greater),
+            ),
+            (
+                custom("22.04"),
+                Version::Semantic(22, 4, 0),
+                Some(Ordering::Equal),
+            ),
+            (
+                Version::Semantic(10, 0, 19045),
+                Version::Semantic(10, 0, 22000),
+                Some(Ordering::Less),
+            ),
+            (
+                custom("10.0.22631.1"),
+                custom("10.0.22631"),
+                Some(Ordering::Greater),
+            ),
+            (
+                extended(&[10, 0, 22631, 3296], None, None),
+                Version::Semantic(10, 0, 22631),
+                Some(Ordering::Greater),
+            ),
+            (
+                extended(&[1, 2, 3, 0], None, None),
+                Version::Semantic(1, 2, 3),
+                Some(Ordering::Equal),
+            ),
+            (
+                extended(&[9, 4], Some("beta"), None),
+                Version::Semantic(9, 4, 0),
+                Some(Ordering::Less),
+            ),
+            (
+                extended(&[1, 2, 3], None, Some("build")),
+                Version::Semantic(1, 2, 3),
+                Some(Ordering::Equal),
+            ),
+            (custom("rawhide"), Version::Semantic(1, 0, 0), None),
+            (Version::Unknown, Version::Semantic(1, 0, 0), None),
+            (Version::Rolling(None), Version::Rolling(None), None),
+        ];
+
+        for (lhs, rhs, expected) in &data {
+            assert_eq!(*expected, lhs.cmp_semantic(rhs), "{lhs} vs {rhs}");
+            assert_eq!(
+                expected.map(Ordering::reverse),
+                rhs.cmp_semantic(lhs),
+                "{rhs} vs {lhs}"
+            );
+        }
+    }
+
+    #[test]
+    fn at_least() {
+        let version = Version::Semantic(22, 4, 0);
+        assert!(version.at_least(&Version::Semantic(22, 4, 0)));
+        assert!(version.at_least(&Version::Custom("20.04".to_owned())));
+        assert!(!version.at_least(&Version::Semantic(24, 4, 0)));
+        assert!(!version.at_least(&Version::Rolling(None)));
+    }
 }
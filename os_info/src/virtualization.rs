@@ -0,0 +1,103 @@
+// spell-checker:ignore bhyve
+
+use std::fmt::{self, Display, Formatter};
+
+/// Hypervisor the operating system is running on.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Virtualization {
+    /// No hypervisor, the operating system runs directly on the hardware.
+    BareMetal,
+    /// KVM (<https://linux-kvm.org/>).
+    Kvm,
+    /// QEMU (<https://www.qemu.org/>).
+    Qemu,
+    /// VMware (<https://www.vmware.com/>).
+    Vmware,
+    /// Microsoft Hyper-V (<https://learn.microsoft.com/en-us/virtualization/hyper-v-on-windows/>).
+    HyperV,
+    /// Xen (<https://xenproject.org/>).
+    Xen,
+    /// Oracle VirtualBox (<https://www.virtualbox.org/>).
+    VirtualBox,
+    /// Firecracker (<https://firecracker-microvm.github.io/>).
+    Firecracker,
+    /// Cloud Hypervisor (<https://www.cloudhypervisor.org/>).
+    CloudHypervisor,
+    /// Amazon EC2 Nitro hypervisor (<https://aws.amazon.com/ec2/nitro/>).
+    Amazon,
+    /// Google Compute Engine (<https://cloud.google.com/compute>).
+    Google,
+    /// Parallels (<https://www.parallels.com/>).
+    Parallels,
+    /// bhyve (<https://bhyve.org/>).
+    Bhyve,
+    /// A hypervisor that was detected, but isn't known.
+    Unknown,
+}
+
+impl Virtualization {
+    /// Returns `true` if the operating system runs in a virtual machine.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_info::Virtualization;
+    ///
+    /// assert!(Virtualization::Kvm.is_virtual());
+    /// assert!(!Virtualization::BareMetal.is_virtual());
+    /// ```
+    pub fn is_virtual(self) -> bool {
+        self != Virtualization::BareMetal
+    }
+}
+
+impl Display for Virtualization {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Virtualization::BareMetal => write!(f, "bare metal"),
+            Virtualization::Kvm => write!(f, "KVM"),
+            Virtualization::Qemu => write!(f, "QEMU"),
+            Virtualization::Vmware => write!(f, "VMware"),
+            Virtualization::HyperV => write!(f, "Hyper-V"),
+            Virtualization::CloudHypervisor => write!(f, "Cloud Hypervisor"),
+            Virtualization::Amazon => write!(f, "Amazon EC2"),
+            Virtualization::Google => write!(f, "Google Compute Engine"),
+            Virtualization::Bhyve => write!(f, "bhyve"),
+            Virtualization::Unknown => write!(f, "unknown hypervisor"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display() {
+        let data = [
+            (Virtualization::BareMetal, "bare metal"),
+            (Virtualization::Kvm, "KVM"),
+            (Virtualization::Qemu, "QEMU"),
+            (Virtualization::Vmware, "VMware"),
+            (Virtualization::HyperV, "Hyper-V"),
+            (Virtualization::Xen, "Xen"),
+            (Virtualization::VirtualBox, "VirtualBox"),
+            (Virtualization::Firecracker, "Firecracker"),
+            (Virtualization::CloudHypervisor, "Cloud Hypervisor"),
+            (Virtualization::Amazon, "Amazon EC2"),
+            (Virtualization::Google, "Google Compute Engine"),
+            (Virtualization::Parallels, "Parallels"),
+            (Virtualization::Bhyve, "bhyve"),
+            (Virtualization::Unknown, "unknown hypervisor"),
+        ];
+
+        for (virtualization, expected) in &data {
+            assert_eq!(&virtualization.to_string(), expected);
+        }
+    }
+}
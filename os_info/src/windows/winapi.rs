@@ -30,7 +30,7 @@ use windows_sys::Win32::{
     UI::WindowsAndMessaging::{GetSystemMetrics, SM_SERVERR2},
 };
 
-use crate::{Bitness, Info, Type, Version};
+use crate::{Bitness, Info, KernelVersion, Type, Version};
 
 #[cfg(target_arch = "x86")]
 #[allow(clippy::upper_case_acronyms)]
@@ -53,11 +53,16 @@ impl Drop for HKeyWrapper {
 pub fn get() -> Info {
     let (version, edition) = version();
     let native_system_info = native_system_info();
+    let kernel_version = match version {
+        Version::Semantic(..) => Some(KernelVersion::parse(version.to_string())),
+        _ => None,
+    };
 
     Info {
         os_type: Type::Windows,
         version,
         edition,
+        kernel_version,
         bitness: bitness(),
         architecture: architecture(native_system_info),
         ..Default::default()
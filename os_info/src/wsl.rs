@@ -0,0 +1,34 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Version of the Windows Subsystem for Linux (<https://learn.microsoft.com/en-us/windows/wsl/>).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum WslVersion {
+    /// WSL 1 that translates the Linux system calls without running a Linux kernel.
+    Wsl1,
+    /// WSL 2 that runs a real Linux kernel in a lightweight virtual machine.
+    Wsl2,
+}
+
+impl Display for WslVersion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            WslVersion::Wsl1 => write!(f, "WSL1"),
+            WslVersion::Wsl2 => write!(f, "WSL2"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display() {
+        assert_eq!("WSL1", WslVersion::Wsl1.to_string());
+        assert_eq!("WSL2", WslVersion::Wsl2.to_string());
+    }
+}